@@ -0,0 +1,566 @@
+use crate::from_dataframe_row_derive::get_inner_type_from_options;
+use syn::{parse::ParseStream, punctuated::Punctuated, Expr, Field, Ident, Lifetime, Token, Type};
+
+const ATTRIBUTE_NAME: &str = "column";
+
+/// The `#[column(...)]` attribute of a field, e.g. `#[column("ts", parse)]`.
+#[derive(Debug, Default)]
+pub struct ColumnAttribute {
+    /// Explicit column name, the first argument that is not a property.
+    pub name: Option<Expr>,
+    pub conversion: Option<Conversion>,
+    /// Value used instead of a null, the field type can't be `Option<...>`
+    pub default: Option<DefaultValue>,
+    /// Value used for every row if the column doesn't exist
+    pub missing: Option<MissingValue>,
+    /// `skip`: the field isn't read from a column but set to its default
+    pub skip: bool,
+    /// `flatten`: the field is a row struct read from the same DataFrame
+    pub flatten: Option<Flatten>,
+    /// `rest`: the field gets the values of all columns not read by other fields
+    pub rest: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct Flatten {
+    /// `prefix = "billing_"`: prepended to the column names of the flattened struct
+    pub prefix: Option<Expr>,
+}
+
+#[derive(Debug)]
+pub enum DefaultValue {
+    /// `default`: `Default::default()`
+    Trait,
+    /// `default = expr`
+    Expr(Expr),
+}
+
+#[derive(Debug)]
+pub enum Conversion {
+    /// `parse`: the column is read as `&str` and converted with `FromStr`
+    FromStr,
+    /// `parse_datetime = "format"`: the column is read as `&str` and converted with the given chrono format
+    Datetime(Expr),
+    /// `with = path::to::fn, from = Type`: the column is read as `Type` and converted with the function
+    With { function: Expr, source_ty: Box<Type> },
+    /// `cast`: the column is cast to the numeric field type, values out of range are row errors
+    Cast,
+}
+
+#[derive(Debug)]
+pub enum MissingValue {
+    /// `missing = "none"`: the field is `None`
+    None,
+    /// `missing = default`: the default of the field, see `DefaultValue`
+    Default,
+}
+
+// Parsed by hand since the column name is an optional positional expression: a generic attribute parser
+// like deluxe takes flags such as `parse` or `default` for a column name given as a path.
+enum ColumnArgument {
+    Name(Expr),
+    Flag(Ident),
+    Property(Ident, Expr),
+    TypeProperty(Ident, Type),
+}
+
+const FLAGS: &[&str] = &["parse", "cast", "default", "skip", "flatten", "rest"];
+const TYPE_PROPERTIES: &[&str] = &["from"];
+
+impl syn::parse::Parse for ColumnArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        if let Ok(ident) = fork.parse::<Ident>() {
+            if fork.peek(Token![=]) && !fork.peek(Token![==]) {
+                input.parse::<Ident>()?;
+                input.parse::<Token![=]>()?;
+                if TYPE_PROPERTIES.iter().any(|property| ident == *property) {
+                    return Ok(ColumnArgument::TypeProperty(ident, input.parse()?));
+                }
+                return Ok(ColumnArgument::Property(ident, input.parse()?));
+            }
+            if (fork.is_empty() || fork.peek(Token![,])) && FLAGS.iter().any(|flag| ident == *flag) {
+                input.parse::<Ident>()?;
+                return Ok(ColumnArgument::Flag(ident));
+            }
+        }
+
+        Ok(ColumnArgument::Name(input.parse()?))
+    }
+}
+
+impl ColumnAttribute {
+    pub fn from_field(field: &Field) -> syn::Result<Self> {
+        let mut attribute = ColumnAttribute::default();
+        let mut with = None;
+        let mut from = None;
+        let mut prefix = None;
+        let field_name = field.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_default();
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+            let arguments = attr.parse_args_with(Punctuated::<ColumnArgument, Token![,]>::parse_terminated)?;
+
+            for argument in arguments {
+                match argument {
+                    ColumnArgument::Name(name) => {
+                        if attribute.name.is_some() {
+                            return Err(syn::Error::new_spanned(
+                                name,
+                                format!("field '{field_name}' can have only one column name"),
+                            ));
+                        }
+                        attribute.name = Some(name);
+                    }
+                    ColumnArgument::Flag(flag) if flag == "parse" => {
+                        attribute.set_conversion(Conversion::FromStr, &flag)?;
+                    }
+                    ColumnArgument::Flag(flag) if flag == "cast" => {
+                        attribute.set_conversion(Conversion::Cast, &flag)?;
+                    }
+                    ColumnArgument::Flag(flag) if flag == "skip" => attribute.skip = true,
+                    ColumnArgument::Flag(flag) if flag == "rest" => attribute.rest = true,
+                    ColumnArgument::Flag(flag) if flag == "flatten" => attribute.flatten = Some(Flatten::default()),
+                    ColumnArgument::Property(key, value) if key == "prefix" => prefix = Some((key, value)),
+                    ColumnArgument::Flag(flag) if flag == "default" => {
+                        attribute.set_default(DefaultValue::Trait, &flag, &field_name)?;
+                    }
+                    ColumnArgument::Property(key, value) if key == "default" => {
+                        attribute.set_default(DefaultValue::Expr(value), &key, &field_name)?;
+                    }
+                    ColumnArgument::Property(key, value) if key == "missing" => {
+                        if attribute.missing.is_some() {
+                            return Err(syn::Error::new_spanned(
+                                key,
+                                format!("field '{field_name}' can have only one 'missing' value"),
+                            ));
+                        }
+                        attribute.missing = Some(parse_missing_value(value)?);
+                    }
+                    ColumnArgument::Property(key, value) if key == "parse_datetime" => {
+                        attribute.set_conversion(Conversion::Datetime(value), &key)?;
+                    }
+                    ColumnArgument::Property(key, value) if key == "with" => with = Some((key, value)),
+                    ColumnArgument::TypeProperty(key, value) if key == "from" => from = Some((key, value)),
+                    ColumnArgument::Flag(key)
+                    | ColumnArgument::Property(key, _)
+                    | ColumnArgument::TypeProperty(key, _) => {
+                        return Err(syn::Error::new_spanned(
+                            &key,
+                            format!("Unsupported 'column' property '{key}'"),
+                        ));
+                    }
+                }
+            }
+        }
+
+        match (with, from) {
+            (Some((key, function)), Some((_, source_ty))) => {
+                if attribute.default.is_some() && is_option(&source_ty) {
+                    return Err(syn::Error::new_spanned(
+                        source_ty,
+                        format!(
+                            "field '{field_name}' has a default, so the type it is read as can't be Option<...>, \
+                             nulls are passed to the 'with' function"
+                        ),
+                    ));
+                }
+                let source_ty = Box::new(add_missing_reference_lifetime(source_ty));
+                attribute.set_conversion(Conversion::With { function, source_ty }, &key)?;
+            }
+            (Some((key, _)), None) => {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    "'with' requires the type the column is read as, e.g. 'from = &str'",
+                ))
+            }
+            (None, Some((key, _))) => {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    "'from' can only be used together with 'with'",
+                ))
+            }
+            (None, None) => {}
+        }
+
+        match (&mut attribute.flatten, prefix) {
+            (Some(flatten), prefix) => flatten.prefix = prefix.map(|(_, value)| value),
+            (None, Some((key, _))) => {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    "'prefix' can only be used together with 'flatten'",
+                ))
+            }
+            (None, None) => {}
+        }
+
+        if attribute.flatten.is_some()
+            && (attribute.name.is_some()
+                || attribute.conversion.is_some()
+                || attribute.default.is_some()
+                || attribute.missing.is_some()
+                || attribute.skip)
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("field '{field_name}' is flattened, so only 'prefix' can be used with 'flatten'"),
+            ));
+        }
+
+        if attribute.rest
+            && (attribute.name.is_some()
+                || attribute.conversion.is_some()
+                || attribute.default.is_some()
+                || attribute.missing.is_some()
+                || attribute.skip
+                || attribute.flatten.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "field '{field_name}' gets the remaining columns, so no other property can be used with 'rest'"
+                ),
+            ));
+        }
+
+        if attribute.skip && (attribute.name.is_some() || attribute.conversion.is_some() || attribute.missing.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("field '{field_name}' is skipped, so only 'default' can be used with 'skip'"),
+            ));
+        }
+
+        Ok(attribute)
+    }
+
+    fn set_conversion(&mut self, conversion: Conversion, ident: &Ident) -> syn::Result<()> {
+        if self.conversion.is_some() {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "only one of 'parse', 'parse_datetime', 'with' and 'cast' can be used",
+            ));
+        }
+        self.conversion = Some(conversion);
+        Ok(())
+    }
+
+    fn set_default(&mut self, default: DefaultValue, ident: &Ident, field_name: &str) -> syn::Result<()> {
+        if self.default.is_some() {
+            return Err(syn::Error::new_spanned(
+                ident,
+                format!("field '{field_name}' can have only one default"),
+            ));
+        }
+        self.default = Some(default);
+        Ok(())
+    }
+}
+
+fn parse_missing_value(value: Expr) -> syn::Result<MissingValue> {
+    match &value {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) if lit.value() == "none" => Ok(MissingValue::None),
+        Expr::Path(path) if path.path.is_ident("default") => Ok(MissingValue::Default),
+        _ => Err(syn::Error::new_spanned(
+            value,
+            "Unsupported 'missing' value, expected \"none\" or default",
+        )),
+    }
+}
+
+fn is_option(ty: &Type) -> bool {
+    let mut is_optional = false;
+    get_inner_type_from_options(ty.clone(), &mut is_optional);
+    is_optional
+}
+
+/// `from = &str` is accepted as shorthand for the row lifetime, since fields can't elide lifetimes.
+fn add_missing_reference_lifetime(ty: Type) -> Type {
+    match ty {
+        Type::Reference(mut type_reference) => {
+            if type_reference.lifetime.is_none() {
+                type_reference.lifetime = Some(Lifetime::new("'a", proc_macro2::Span::call_site()));
+            }
+            Type::Reference(type_reference)
+        }
+        Type::Path(mut type_path) => {
+            // Option<&str>
+            for segment in type_path.path.segments.iter_mut() {
+                if let syn::PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                    for argument in arguments.args.iter_mut() {
+                        if let syn::GenericArgument::Type(inner) = argument {
+                            *inner = add_missing_reference_lifetime(inner.clone());
+                        }
+                    }
+                }
+            }
+            Type::Path(type_path)
+        }
+        ty => ty,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+    use syn::parse_quote;
+
+    fn parse_field(field: Field) -> syn::Result<ColumnAttribute> {
+        ColumnAttribute::from_field(&field)
+    }
+
+    #[test]
+    fn name_and_flags() {
+        let attribute = parse_field(parse_quote! {
+            #[column("ts", parse)]
+            ts: u64
+        })
+        .unwrap();
+
+        let name = attribute.name.unwrap();
+        assert_eq!(quote!(#name).to_string(), "\"ts\"");
+        assert!(matches!(attribute.conversion, Some(Conversion::FromStr)));
+    }
+
+    #[test]
+    fn constant_names_are_not_flags() {
+        let attribute = parse_field(parse_quote! {
+            #[column(COLUMN_NAME)]
+            value: u64
+        })
+        .unwrap();
+
+        let name = attribute.name.unwrap();
+        assert_eq!(quote!(#name).to_string(), "COLUMN_NAME");
+    }
+
+    #[test]
+    fn properties() {
+        let attribute = parse_field(parse_quote! {
+            #[column(parse_datetime = "%Y-%m-%d")]
+            date: NaiveDate
+        })
+        .unwrap();
+
+        assert!(attribute.name.is_none());
+        match attribute.conversion {
+            Some(Conversion::Datetime(format)) => assert_eq!(quote!(#format).to_string(), "\"%Y-%m-%d\""),
+            conversion => panic!("unexpected {conversion:?}"),
+        }
+    }
+
+    #[test]
+    fn with_function_and_source_type() {
+        let attribute = parse_field(parse_quote! {
+            #[column("raw", with = convert::cents, from = Option<&str>)]
+            price: Price
+        })
+        .unwrap();
+
+        match attribute.conversion {
+            Some(Conversion::With { function, source_ty }) => {
+                assert_eq!(quote!(#function).to_string(), "convert :: cents");
+                assert_eq!(quote!(#source_ty).to_string(), "Option < & 'a str >");
+            }
+            conversion => panic!("unexpected {conversion:?}"),
+        }
+    }
+
+    #[test]
+    fn default_requires_non_optional_source_type() {
+        let err = parse_field(parse_quote! {
+            #[column(with = convert::cents, from = Option<&str>, default)]
+            price: Price
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "field 'price' has a default, so the type it is read as can't be Option<...>, \
+             nulls are passed to the 'with' function"
+        );
+    }
+
+    #[test]
+    fn with_requires_source_type() {
+        let err = parse_field(parse_quote! {
+            #[column(with = convert::cents)]
+            price: Price
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "'with' requires the type the column is read as, e.g. 'from = &str'"
+        );
+    }
+
+    #[test]
+    fn multiple_conversions_are_rejected() {
+        let err = parse_field(parse_quote! {
+            #[column(parse, cast)]
+            value: u8
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "only one of 'parse', 'parse_datetime', 'with' and 'cast' can be used"
+        );
+    }
+
+    #[test]
+    fn defaults() {
+        let attribute = parse_field(parse_quote! {
+            #[column(default)]
+            value: u64
+        })
+        .unwrap();
+
+        assert!(matches!(attribute.default, Some(DefaultValue::Trait)));
+
+        let attribute = parse_field(parse_quote! {
+            #[column("v", default = -1, cast)]
+            value: i8
+        })
+        .unwrap();
+
+        match attribute.default {
+            Some(DefaultValue::Expr(expr)) => assert_eq!(quote!(#expr).to_string(), "- 1"),
+            default => panic!("unexpected {default:?}"),
+        }
+        assert!(matches!(attribute.conversion, Some(Conversion::Cast)));
+    }
+
+    #[test]
+    fn missing_values() {
+        let attribute = parse_field(parse_quote! {
+            #[column(missing = "none")]
+            value: Option<u64>
+        })
+        .unwrap();
+
+        assert!(matches!(attribute.missing, Some(MissingValue::None)));
+
+        let attribute = parse_field(parse_quote! {
+            #[column(missing = default, default = 5)]
+            value: u64
+        })
+        .unwrap();
+
+        assert!(matches!(attribute.missing, Some(MissingValue::Default)));
+
+        let err = parse_field(parse_quote! {
+            #[column(missing = "skip")]
+            value: u64
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unsupported 'missing' value, expected \"none\" or default"
+        );
+    }
+
+    #[test]
+    fn skip() {
+        let attribute = parse_field(parse_quote! {
+            #[column(skip, default = Vec::new())]
+            cache: Vec<u64>
+        })
+        .unwrap();
+
+        assert!(attribute.skip);
+        assert!(matches!(attribute.default, Some(DefaultValue::Expr(_))));
+
+        let err = parse_field(parse_quote! {
+            #[column("cache", skip)]
+            cache: Vec<u64>
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "field 'cache' is skipped, so only 'default' can be used with 'skip'"
+        );
+    }
+
+    #[test]
+    fn flatten() {
+        let attribute = parse_field(parse_quote! {
+            #[column(flatten, prefix = "billing_")]
+            billing: Address
+        })
+        .unwrap();
+
+        let prefix = attribute.flatten.unwrap().prefix.unwrap();
+        assert_eq!(quote!(#prefix).to_string(), "\"billing_\"");
+
+        let err = parse_field(parse_quote! {
+            #[column(prefix = "billing_")]
+            billing: Address
+        })
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "'prefix' can only be used together with 'flatten'");
+
+        let err = parse_field(parse_quote! {
+            #[column(flatten, default)]
+            billing: Address
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "field 'billing' is flattened, so only 'prefix' can be used with 'flatten'"
+        );
+    }
+
+    #[test]
+    fn rest() {
+        let attribute = parse_field(parse_quote! {
+            #[column(rest)]
+            extra: HashMap<String, AnyValue<'a>>
+        })
+        .unwrap();
+
+        assert!(attribute.rest);
+
+        let err = parse_field(parse_quote! {
+            #[column("extra", rest)]
+            extra: HashMap<String, AnyValue<'a>>
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "field 'extra' gets the remaining columns, so no other property can be used with 'rest'"
+        );
+    }
+
+    #[test]
+    fn multiple_names_are_rejected() {
+        let err = parse_field(parse_quote! {
+            #[column("a")]
+            #[column("b")]
+            value: u64
+        })
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "field 'value' can have only one column name");
+    }
+
+    #[test]
+    fn unknown_properties_are_rejected() {
+        let err = parse_field(parse_quote! {
+            #[column(format = "%Y")]
+            value: u64
+        })
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unsupported 'column' property 'format'");
+    }
+}
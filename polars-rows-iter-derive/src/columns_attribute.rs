@@ -0,0 +1,198 @@
+use syn::{punctuated::Punctuated, Expr, Field, MetaNameValue, Token};
+
+const ATTRIBUTE_NAME: &str = "columns";
+
+/// The `#[columns(...)]` attribute of a field read from all columns matching a regex,
+/// e.g. `#[columns(regex = "^feat_\\d+$", order = "natural")]`.
+#[derive(Debug)]
+pub struct ColumnsAttribute {
+    /// `regex = "..."`: matched against the column names of the DataFrame
+    pub regex: Expr,
+    /// `order = "natural"`: the columns are sorted with numbers compared by value instead of kept in column order
+    pub natural_order: bool,
+}
+
+impl ColumnsAttribute {
+    pub fn from_field(field: &Field) -> syn::Result<Option<Self>> {
+        let field_name = field.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_default();
+
+        let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) else {
+            return Ok(None);
+        };
+
+        if field.attrs.iter().any(|attr| attr.path().is_ident("column")) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("field '{field_name}' is read from all matching columns, so #[column(...)] can't be used"),
+            ));
+        }
+
+        let mut regex = None;
+        let mut natural_order = false;
+
+        let arguments = attr.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+        for argument in arguments {
+            if argument.path.is_ident("regex") {
+                regex = Some(argument.value);
+            } else if argument.path.is_ident("order") {
+                natural_order = parse_order(argument.value)?;
+            } else {
+                return Err(syn::Error::new_spanned(
+                    &argument.path,
+                    "Unsupported 'columns' property, expected 'regex' or 'order'",
+                ));
+            }
+        }
+
+        let regex = regex.ok_or_else(|| {
+            syn::Error::new_spanned(
+                attr,
+                format!("field '{field_name}' requires a regex, e.g. 'regex = \"^feat_\"'"),
+            )
+        })?;
+
+        if cfg!(not(feature = "regex")) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                format!(
+                    "field '{field_name}' has #[columns(...)], which requires the 'regex' feature of polars-rows-iter"
+                ),
+            ));
+        }
+
+        #[cfg(feature = "regex")]
+        check_literal_regex(&regex)?;
+
+        Ok(Some(ColumnsAttribute { regex, natural_order }))
+    }
+}
+
+/// Literal regexes are checked at compile time, other expressions when the iterator is created.
+#[cfg(feature = "regex")]
+fn check_literal_regex(regex: &Expr) -> syn::Result<()> {
+    if let Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+    }) = regex
+    {
+        if let Err(err) = regex::Regex::new(&lit.value()) {
+            return Err(syn::Error::new_spanned(lit, format!("Invalid column regex: {err}")));
+        }
+    }
+    Ok(())
+}
+
+fn parse_order(value: Expr) -> syn::Result<bool> {
+    match &value {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) if lit.value() == "column" => Ok(false),
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) if lit.value() == "natural" => Ok(true),
+        _ => Err(syn::Error::new_spanned(
+            value,
+            "Unsupported 'order' value, expected \"column\" or \"natural\"",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_and_order() {
+        use quote::quote;
+
+        let attribute = ColumnsAttribute::from_field(&parse_quote! {
+            #[columns(regex = "^feat_\\d+$", order = "natural")]
+            features: Vec<f32>
+        })
+        .unwrap()
+        .unwrap();
+
+        let regex = attribute.regex;
+        assert_eq!(quote!(#regex).to_string(), "\"^feat_\\\\d+$\"");
+        assert!(attribute.natural_order);
+    }
+
+    #[test]
+    fn fields_without_attribute() {
+        let attribute = ColumnsAttribute::from_field(&parse_quote! {
+            #[column("value")]
+            value: u64
+        })
+        .unwrap();
+
+        assert!(attribute.is_none());
+    }
+
+    #[test]
+    fn invalid_attributes_are_rejected() {
+        let err = ColumnsAttribute::from_field(&parse_quote! {
+            #[columns(order = "natural")]
+            features: Vec<f32>
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "field 'features' requires a regex, e.g. 'regex = \"^feat_\"'"
+        );
+
+        let err = ColumnsAttribute::from_field(&parse_quote! {
+            #[columns(regex = "^feat_", order = "name")]
+            features: Vec<f32>
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unsupported 'order' value, expected \"column\" or \"natural\""
+        );
+
+        let err = ColumnsAttribute::from_field(&parse_quote! {
+            #[columns(regex = "^feat_")]
+            #[column(default)]
+            features: Vec<f32>
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "field 'features' is read from all matching columns, so #[column(...)] can't be used"
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn invalid_literal_regexes_are_rejected() {
+        let err = ColumnsAttribute::from_field(&parse_quote! {
+            #[columns(regex = "feat_(")]
+            features: Vec<f32>
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().starts_with("Invalid column regex: "), "{err}");
+    }
+
+    #[cfg(not(feature = "regex"))]
+    #[test]
+    fn regex_feature_is_required() {
+        let err = ColumnsAttribute::from_field(&parse_quote! {
+            #[columns(regex = "^feat_")]
+            features: Vec<f32>
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "field 'features' has #[columns(...)], which requires the 'regex' feature of polars-rows-iter"
+        );
+    }
+}
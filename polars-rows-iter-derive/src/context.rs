@@ -1,4 +1,5 @@
-use crate::{field_info::FieldInfo, from_dataframe_attribute::FromDataFrameAttribute};
+use crate::{column_attribute::DefaultValue, field_info::FieldInfo, from_dataframe_attribute::FromDataFrameAttribute};
+use std::ops::Range;
 use syn::{Ident, TypeParam};
 
 pub struct Context {
@@ -6,7 +7,52 @@ pub struct Context {
     pub builder_struct_ident: Ident,
     pub iter_struct_ident: Ident,
     pub fields_list: Vec<FieldInfo>,
+    /// Fields with `#[column(skip)]` or `#[row_index]`, which aren't read from any column.
+    pub skipped_fields: Vec<SkippedField>,
     pub has_lifetime: bool,
     pub type_generics: Vec<TypeParam>,
     pub attributes: FromDataFrameAttribute,
+    /// Set for enums, which select the variant of each row by the value of the tag column.
+    pub variants: Option<Vec<VariantInfo>>,
+}
+
+impl Context {
+    /// Without the tag column or a field that is always read from a column there's no iterator that ends
+    /// with the DataFrame, so the rows are counted by the iterator itself.
+    pub fn counts_rows(&self) -> bool {
+        self.variants.is_none() && self.fields_list.iter().all(|field_info| field_info.missing.is_some())
+    }
+
+    /// The row position is tracked by the iterator for fields with `#[row_index]`.
+    pub fn has_row_index(&self) -> bool {
+        self.skipped_fields.iter().any(|skipped_field| skipped_field.row_index)
+    }
+
+    /// Structs with `#[columns(...)]` or `#[column(rest)]` fields can't be flattened, see `FromDataFrameRowMapped`.
+    pub fn can_be_flattened(&self) -> bool {
+        self.fields_list
+            .iter()
+            .all(|field_info| field_info.columns.is_none() && !field_info.rest)
+    }
+
+    /// The names of all columns that are looked up are recorded for fields with `#[column(rest)]`.
+    pub fn has_rest_fields(&self) -> bool {
+        self.fields_list.iter().any(|field_info| field_info.rest)
+    }
+}
+
+pub struct VariantInfo {
+    pub ident: Ident,
+    pub tag_value: String,
+    /// The range of the variant's fields in `Context::fields_list`.
+    pub fields: Range<usize>,
+    /// The range of the variant's fields in `Context::skipped_fields`.
+    pub skipped_fields: Range<usize>,
+}
+
+pub struct SkippedField {
+    pub ident: Ident,
+    pub default: Option<DefaultValue>,
+    /// `#[row_index]`: the field is set to the position of the row instead of its default
+    pub row_index: bool,
 }
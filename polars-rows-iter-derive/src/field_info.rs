@@ -1,5 +1,10 @@
-use crate::context::Context;
-use proc_macro2::TokenStream;
+use crate::{
+    column_attribute::{Conversion, DefaultValue, Flatten, MissingValue},
+    columns_attribute::ColumnsAttribute,
+    context::Context,
+    from_dataframe_row_derive::get_inner_type_from_options,
+};
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{Expr, Ident, Type};
 
@@ -10,11 +15,49 @@ pub struct FieldInfo {
     pub dtype_ident: Ident,
     pub iter_ident: Ident,
     pub inner_ty: Type,
+    /// The type the column is read as, differs from `inner_ty` for converted fields.
+    pub source_ty: Type,
     pub is_optional: bool,
     pub column_name_expr: Expr,
+    pub conversion: Option<Conversion>,
+    pub default: Option<DefaultValue>,
+    pub missing: Option<MissingValue>,
+    pub flatten: Option<Flatten>,
+    /// Set for fields with `#[columns(...)]`, which are read from all matching columns.
+    pub columns: Option<MatchingColumns>,
+    /// `#[column(rest)]`: the field gets the values of all columns not read by other fields.
+    pub rest: bool,
+}
+
+#[derive(Debug)]
+pub struct MatchingColumns {
+    pub attribute: ColumnsAttribute,
+    /// `HashMap<String, T>` or `BTreeMap<String, T>` keyed by column name, otherwise `Vec<T>`
+    pub is_map: bool,
 }
 
 impl FieldInfo {
+    /// Whether the field is read from a single column with a name known by the builder.
+    pub fn has_single_column(&self) -> bool {
+        self.flatten.is_none() && self.columns.is_none() && !self.rest
+    }
+
+    pub fn value_ident(&self) -> Ident {
+        Ident::new(&format!("{}_value", self.name), Span::call_site())
+    }
+
+    /// Whether the value is read as `Option<...>` before it is converted, `is_optional` refers to the field type.
+    pub fn is_source_optional(&self) -> bool {
+        match &self.conversion {
+            Some(Conversion::With { source_ty, .. }) => {
+                let mut is_optional = false;
+                get_inner_type_from_options(source_ty.as_ref().clone(), &mut is_optional);
+                is_optional
+            }
+            _ => self.is_optional,
+        }
+    }
+
     pub fn create_default_column_name(&self, ctx: &Context) -> TokenStream {
         let default_column_name_expr = &self.column_name_expr;
         let prefix = ctx.attributes.prefix.as_ref();
@@ -83,8 +126,15 @@ mod tests {
             dtype_ident: parse_quote!(test_dtype),
             iter_ident: parse_quote!(test_iter),
             inner_ty: parse_quote!(String),
+            source_ty: parse_quote!(String),
             is_optional: false,
             column_name_expr,
+            conversion: None,
+            default: None,
+            missing: None,
+            flatten: None,
+            columns: None,
+            rest: false,
         }
     }
 
@@ -98,13 +148,18 @@ mod tests {
             builder_struct_ident: parse_quote!(TestStructBuilder),
             iter_struct_ident: parse_quote!(TestStructIterator),
             fields_list: vec![],
+            skipped_fields: vec![],
             has_lifetime: false,
             type_generics: vec![],
             attributes: FromDataFrameAttribute {
                 convert_case,
                 prefix,
                 postfix,
+                tag: None,
+                lossy_cast: false,
+                row_index_offset: None,
             },
+            variants: None,
         }
     }
 
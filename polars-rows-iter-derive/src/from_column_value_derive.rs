@@ -0,0 +1,264 @@
+use crate::from_dataframe_attribute::parse_property_value;
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
+use syn::{DeriveInput, Fields, Ident, LitStr};
+
+const ATTRIBUTE_NAME: &str = "from_column_value";
+
+pub fn from_column_value_derive_impl(ast: DeriveInput) -> syn::Result<TokenStream> {
+    let enum_data = match &ast.data {
+        syn::Data::Enum(data_enum) => data_enum,
+        syn::Data::Struct(s) => {
+            return Err(syn::Error::new_spanned(
+                s.struct_token,
+                "FromColumnValue can only be derived for enums",
+            ))
+        }
+        syn::Data::Union(u) => {
+            return Err(syn::Error::new_spanned(
+                u.union_token,
+                "FromColumnValue can only be derived for enums",
+            ))
+        }
+    };
+
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            "FromColumnValue cannot be derived for generic enums",
+        ));
+    }
+
+    let convert_case = parse_enum_attribute(&ast)?;
+
+    let mut names = HashSet::new();
+    let variants = enum_data
+        .variants
+        .iter()
+        .map(|variant| {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new_spanned(
+                    &variant.fields,
+                    "FromColumnValue can only be derived for enums with fieldless variants",
+                ));
+            }
+
+            let name = match parse_variant_attribute(variant)? {
+                Some(rename) => rename.value(),
+                None => match convert_case {
+                    Some(case) => variant.ident.to_string().to_case(case),
+                    None => variant.ident.to_string(),
+                },
+            };
+
+            // The value of a row would be ambiguous
+            if !names.insert(name.clone()) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    format!("duplicate column value name '{name}'"),
+                ));
+            }
+
+            Ok((variant.ident.clone(), name))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let has_explicit_discriminants = enum_data.variants.iter().any(|v| v.discriminant.is_some());
+
+    let enum_ident = &ast.ident;
+    let enum_name = enum_ident.to_string();
+
+    let variant_names = variants.iter().map(|(_, name)| name);
+    let variant_idents = variants.iter().map(|(ident, _)| ident).collect::<Vec<_>>();
+    let variant_indexes = 0..variant_idents.len();
+
+    let discriminants = match has_explicit_discriminants {
+        true => quote! { Some(&[#(#enum_ident::#variant_idents as i128,)*]) },
+        false => quote! { None },
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl ::polars_rows_iter::FromColumnValue for #enum_ident {
+            const TYPE_NAME: &'static str = #enum_name;
+            const VARIANT_NAMES: &'static [&'static str] = &[#(#variant_names,)*];
+            const DISCRIMINANTS: Option<&'static [i128]> = #discriminants;
+
+            fn from_variant_index(index: usize) -> Self {
+                match index {
+                    #(#variant_indexes => #enum_ident::#variant_idents,)*
+                    _ => unreachable!("invalid variant index {index} for {}", #enum_name),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a> ::polars_rows_iter::IterFromColumn<'a> for #enum_ident {
+            type RawInner = ::polars::prelude::PolarsResult<#enum_ident>;
+            fn create_iter(
+                column: &'a ::polars::prelude::Column,
+            ) -> ::polars::prelude::PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                ::polars_rows_iter::create_column_value_iter::<#enum_ident>(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                column_name: &str,
+                _dtype: &::polars::prelude::DataType,
+            ) -> ::polars::prelude::PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                polars_value.ok_or_else(|| {
+                    <#enum_ident as ::polars_rows_iter::IterFromColumn<'a>>::unexpected_null_value_error(column_name)
+                })?
+            }
+        }
+
+        #[automatically_derived]
+        impl ::polars_rows_iter::NullableIterFromColumn for #enum_ident {}
+    })
+}
+
+fn parse_enum_attribute(ast: &DeriveInput) -> syn::Result<Option<Case<'static>>> {
+    let mut convert_case = None;
+
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("convert_case") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let case_ident: Ident = content.parse()?;
+                convert_case = Some(parse_case(&case_ident)?);
+            } else {
+                return Err(meta.error("Unsupported 'from_column_value' property on enum"));
+            }
+            Ok(())
+        })?
+    }
+
+    Ok(convert_case)
+}
+
+fn parse_variant_attribute(variant: &syn::Variant) -> syn::Result<Option<LitStr>> {
+    let mut rename = None;
+
+    for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                rename = Some(parse_property_value(&meta)?);
+            } else {
+                return Err(meta.error("Unsupported 'from_column_value' property on variant"));
+            }
+            Ok(())
+        })?
+    }
+
+    Ok(rename)
+}
+
+/// The case is applied at compile time, so it has to be one of the unit variants of `convert_case::Case`.
+fn parse_case(case_ident: &Ident) -> syn::Result<Case<'static>> {
+    let case_name = case_ident.to_string();
+
+    Case::all_cases()
+        .iter()
+        .find(|case| format!("{case:?}") == case_name)
+        .copied()
+        .ok_or_else(|| syn::Error::new_spanned(case_ident, format!("Unknown case '{case_name}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn expand(ast: DeriveInput) -> String {
+        from_column_value_derive_impl(ast).unwrap().to_string()
+    }
+
+    #[test]
+    fn variant_names_follow_case_and_rename() {
+        let result = expand(parse_quote! {
+            #[from_column_value(convert_case(Snake))]
+            enum OrderStatus {
+                InTransit,
+                #[from_column_value(rename("done"))]
+                Delivered,
+                #[from_column_value(rename = "lost")]
+                Missing,
+            }
+        });
+
+        assert!(result.contains("& [\"in_transit\" , \"done\" , \"lost\" ,]"));
+        assert!(result.contains("DISCRIMINANTS : Option < & 'static [i128] > = None"));
+    }
+
+    #[test]
+    fn duplicate_variant_names_are_rejected() {
+        let err = from_column_value_derive_impl(parse_quote! {
+            enum OrderStatus {
+                Open,
+                #[from_column_value(rename("Open"))]
+                Reopened,
+            }
+        })
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "duplicate column value name 'Open'");
+
+        let err = from_column_value_derive_impl(parse_quote! {
+            #[from_column_value(convert_case(Snake))]
+            enum OrderStatus {
+                InTransit,
+                In_Transit,
+            }
+        })
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "duplicate column value name 'in_transit'");
+    }
+
+    #[test]
+    fn explicit_discriminants_are_exposed() {
+        let result = expand(parse_quote! {
+            enum Level {
+                Low = 1,
+                High = 10,
+            }
+        });
+
+        assert!(result.contains("Some (& [Level :: Low as i128 , Level :: High as i128 ,])"));
+    }
+
+    #[test]
+    fn variants_with_fields_are_rejected() {
+        let err = from_column_value_derive_impl(parse_quote! {
+            enum Shape {
+                Circle(f64),
+            }
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "FromColumnValue can only be derived for enums with fieldless variants"
+        );
+    }
+
+    #[test]
+    fn unknown_case_is_rejected() {
+        let err = from_column_value_derive_impl(parse_quote! {
+            #[from_column_value(convert_case(Shouting))]
+            enum Level {
+                Low,
+            }
+        })
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unknown case 'Shouting'");
+    }
+}
@@ -3,6 +3,11 @@ pub struct FromDataFrameAttribute {
     pub convert_case: Option<syn::Expr>,
     pub prefix: Option<syn::Expr>,
     pub postfix: Option<syn::Expr>,
+    pub tag: Option<syn::Expr>,
+    /// `cast = "lossy"`: numeric fields are read as if they had `#[column(cast)]`
+    pub lossy_cast: bool,
+    /// `row_index_offset = expr`: added to the row positions of `#[row_index]` fields
+    pub row_index_offset: Option<syn::Expr>,
 }
 
 impl FromDataFrameAttribute {
@@ -10,6 +15,9 @@ impl FromDataFrameAttribute {
         let mut convert_case = None;
         let mut prefix = None;
         let mut postfix = None;
+        let mut tag = None;
+        let mut lossy_cast = false;
+        let mut row_index_offset = None;
 
         for attr in &input.attrs {
             if !attr.meta.path().is_ident("from_dataframe") {
@@ -35,6 +43,19 @@ impl FromDataFrameAttribute {
                     let content;
                     syn::parenthesized!(content in meta.input);
                     postfix = Some(content.parse()?);
+                } else if meta.path.is_ident("tag") {
+                    tag = Some(parse_property_value(&meta)?);
+                } else if meta.path.is_ident("cast") {
+                    let mode: syn::LitStr = parse_property_value(&meta)?;
+                    if mode.value() != "lossy" {
+                        return Err(syn::Error::new_spanned(
+                            mode,
+                            "Unsupported cast mode, only \"lossy\" is supported",
+                        ));
+                    }
+                    lossy_cast = true;
+                } else if meta.path.is_ident("row_index_offset") {
+                    row_index_offset = Some(parse_property_value(&meta)?);
                 } else {
                     return Err(meta.error("Unsupported 'from_dataframe' property"));
                 }
@@ -46,10 +67,54 @@ impl FromDataFrameAttribute {
             convert_case,
             prefix,
             postfix,
+            tag,
+            lossy_cast,
+            row_index_offset,
         })
     }
 }
 
+/// Attributes on the variants of enums deriving `FromDataFrameRow`.
+#[derive(Debug, Default)]
+pub struct FromDataFrameVariantAttribute {
+    pub rename: Option<syn::LitStr>,
+}
+
+impl FromDataFrameVariantAttribute {
+    pub fn from_variant(variant: &syn::Variant) -> Result<Self, syn::Error> {
+        let mut rename = None;
+
+        for attr in &variant.attrs {
+            if !attr.meta.path().is_ident("from_dataframe") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    rename = Some(parse_property_value(&meta)?);
+                } else {
+                    return Err(meta.error("Unsupported 'from_dataframe' property on enum variant"));
+                }
+                Ok(())
+            })?
+        }
+
+        Ok(Self { rename })
+    }
+}
+
+/// Accepts both `name("value")` and `name = "value"`.
+/// Accepts both `name(value)` and `name = value`.
+pub fn parse_property_value<T: syn::parse::Parse>(meta: &syn::meta::ParseNestedMeta) -> Result<T, syn::Error> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse()
+    } else {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        content.parse()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -67,4 +132,56 @@ mod tests {
 
         println!("{attr:?}");
     }
+
+    #[test]
+    fn parse_tag_test() {
+        let input = quote::quote! {
+            #[from_dataframe(tag = "kind")]
+            enum Test {}
+        };
+
+        let ast: syn::DeriveInput = syn::parse2(input).unwrap();
+
+        let attr = super::FromDataFrameAttribute::from_ast(&ast).unwrap();
+        let tag = attr.tag.unwrap();
+
+        assert_eq!(quote::quote!(#tag).to_string(), "\"kind\"");
+    }
+
+    #[test]
+    fn parse_cast_test() {
+        let input = quote::quote! {
+            #[from_dataframe(cast = "lossy")]
+            struct Test {}
+        };
+
+        let ast: syn::DeriveInput = syn::parse2(input).unwrap();
+
+        assert!(super::FromDataFrameAttribute::from_ast(&ast).unwrap().lossy_cast);
+
+        let input = quote::quote! {
+            #[from_dataframe(cast = "always")]
+            struct Test {}
+        };
+
+        let ast: syn::DeriveInput = syn::parse2(input).unwrap();
+        let err = super::FromDataFrameAttribute::from_ast(&ast).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unsupported cast mode, only \"lossy\" is supported");
+    }
+
+    #[test]
+    fn parse_row_index_offset_test() {
+        let input = quote::quote! {
+            #[from_dataframe(row_index_offset = 1)]
+            struct Test {}
+        };
+
+        let ast: syn::DeriveInput = syn::parse2(input).unwrap();
+
+        let attr = super::FromDataFrameAttribute::from_ast(&ast).unwrap();
+        let offset = attr.row_index_offset.unwrap();
+
+        assert_eq!(quote::quote!(#offset).to_string(), "1");
+    }
 }
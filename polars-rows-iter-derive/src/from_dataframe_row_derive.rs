@@ -1,7 +1,14 @@
-use crate::{context::Context, field_info::FieldInfo, from_dataframe_attribute::FromDataFrameAttribute};
+use crate::{
+    column_attribute::{ColumnAttribute, Conversion, DefaultValue, Flatten, MissingValue},
+    columns_attribute::ColumnsAttribute,
+    context::{Context, SkippedField, VariantInfo},
+    field_info::{FieldInfo, MatchingColumns},
+    from_dataframe_attribute::{FromDataFrameAttribute, FromDataFrameVariantAttribute},
+};
+use convert_case::{Case, Casing};
 use itertools::Itertools;
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{
     punctuated::Punctuated, spanned::Spanned, DeriveInput, Expr, ExprLit, Field, GenericArgument, GenericParam,
     Generics, Ident, Lifetime, LifetimeParam, LitStr, PathArguments, Token, Type, TypeParam, TypeReference,
@@ -13,22 +20,6 @@ const ROW_ITERATOR_NAME: &str = "RowsIterator";
 pub fn from_dataframe_row_derive_impl(ast: DeriveInput) -> syn::Result<TokenStream> {
     let attributes = FromDataFrameAttribute::from_ast(&ast)?;
 
-    let struct_data = match &ast.data {
-        syn::Data::Struct(data_struct) => data_struct,
-        syn::Data::Enum(e) => {
-            return Err(syn::Error::new_spanned(
-                e.enum_token,
-                "FromDataFrameRow cannot be derived for enums",
-            ))
-        }
-        syn::Data::Union(u) => {
-            return Err(syn::Error::new_spanned(
-                u.union_token,
-                "FromDataFrameRow cannot be derived for unions",
-            ))
-        }
-    };
-
     let struct_ident = ast.ident.clone();
     let struct_ident_str = struct_ident.to_string();
 
@@ -37,12 +28,42 @@ pub fn from_dataframe_row_derive_impl(ast: DeriveInput) -> syn::Result<TokenStre
         Span::call_site(),
     );
 
-    let fields_list: Vec<FieldInfo> = struct_data
-        .fields
-        .iter()
-        .cloned()
-        .map(create_iterator_struct_field_info)
-        .collect::<syn::Result<Vec<_>>>()?;
+    let (fields_list, skipped_fields, variants) = match &ast.data {
+        syn::Data::Struct(data_struct) => {
+            if let Some(tag) = &attributes.tag {
+                return Err(syn::Error::new_spanned(
+                    tag,
+                    "#[from_dataframe(tag = ...)] is only supported on enums",
+                ));
+            }
+            let mut fields_list = Vec::new();
+            let mut skipped_fields = Vec::new();
+            create_field_infos(
+                &data_struct.fields,
+                None,
+                attributes.lossy_cast,
+                &mut fields_list,
+                &mut skipped_fields,
+            )?;
+            (fields_list, skipped_fields, None)
+        }
+        syn::Data::Enum(data_enum) => {
+            if attributes.tag.is_none() {
+                return Err(syn::Error::new_spanned(
+                    data_enum.enum_token,
+                    "FromDataFrameRow can only be derived for enums with a #[from_dataframe(tag = \"...\")] attribute",
+                ));
+            }
+            let (fields_list, skipped_fields, variants) = create_variant_infos(data_enum, attributes.lossy_cast)?;
+            (fields_list, skipped_fields, Some(variants))
+        }
+        syn::Data::Union(u) => {
+            return Err(syn::Error::new_spanned(
+                u.union_token,
+                "FromDataFrameRow cannot be derived for unions",
+            ))
+        }
+    };
 
     let has_lifetime = match ast.generics.lifetimes().count() {
         0 => false,
@@ -63,9 +84,11 @@ pub fn from_dataframe_row_derive_impl(ast: DeriveInput) -> syn::Result<TokenStre
         builder_struct_ident,
         iter_struct_ident,
         fields_list,
+        skipped_fields,
         has_lifetime,
         type_generics: ast.generics.type_params().cloned().collect(),
         attributes,
+        variants,
     };
 
     let builder_struct = create_builder_struct(&ctx);
@@ -172,9 +195,12 @@ fn create_builder_struct(ctx: &Context) -> proc_macro2::TokenStream {
 fn create_builder_struct_impl(ctx: &Context) -> proc_macro2::TokenStream {
     let builder_struct_ident = &ctx.builder_struct_ident;
 
+    // The columns of flattened fields are named by the flattened struct, the ones of `#[columns(...)]` by the regex
     let field_column_func_list = ctx
         .fields_list
         .iter()
+        .filter(|f| f.has_single_column())
+        .unique_by(|f| &f.ident)
         .map(|f| {
             let field_ident = &f.ident;
             let field_name = f.ident.to_string();
@@ -215,7 +241,20 @@ fn create_row_struct_impl(ctx: &Context) -> proc_macro2::TokenStream {
     let struct_generics = create_struct_generics(ctx, ctx.has_lifetime.then_some(&lifetime));
     let where_clause = create_where_clause(ctx, &lifetime, false);
 
-    let column_name_expr_list = ctx.fields_list.iter().map(|f| &f.column_name_expr).collect_vec();
+    let column_name_expr_list = ctx
+        .attributes
+        .tag
+        .iter()
+        .filter(|_| ctx.variants.is_some())
+        .chain(
+            ctx.fields_list
+                .iter()
+                .filter(|f| f.has_single_column())
+                .map(|f| &f.column_name_expr),
+        )
+        // Fields of different variants can share a column while having different names, and the other way around
+        .unique_by(|expr| expr.to_token_stream().to_string())
+        .collect_vec();
 
     let stream = quote::quote! {
         #[automatically_derived]
@@ -251,28 +290,132 @@ fn create_from_dataframe_row_trait_impl(ctx: &Context) -> proc_macro2::TokenStre
 
         let default_column_name = f.create_default_column_name(ctx);
 
-        let field_type = remove_lifetime(f.inner_ty.clone());
+        let field_type = remove_lifetime(f.source_ty.clone());
+
+        if let Some(Flatten { prefix }) = &f.flatten {
+            let prefix = prefix.as_ref().map_or_else(|| quote! { "" }, |prefix| quote! { #prefix });
+            return quote! {
+
+                let #ident_iter = <#field_type as ::polars_rows_iter::FromDataFrameRowMapped<#lifetime>>::from_dataframe_mapped(
+                    dataframe,
+                    std::collections::HashMap::new(),
+                    &|flattened_column_name: &str| {
+                        let flattened_column_name = format!("{}{flattened_column_name}", #prefix);
+                        let flattened_column_name = flattened_column_name.as_str();
+                        #default_column_name
+                        map_column_name(default_column_name)
+                    },
+                )?;
+            };
+        }
+
+        // Fields with `#[column(rest)]` are created last, after all other columns are claimed
+        if f.rest {
+            return quote! {};
+        }
+
+        if let Some(MatchingColumns { attribute, .. }) = &f.columns {
+            let regex = &attribute.regex;
+            let natural_order = attribute.natural_order;
+            // Claimed through `map_column_name` like all other columns
+            let claim_columns = ctx.has_rest_fields().then(|| {
+                quote! {
+                    #ident_iter.column_names().for_each(|column_name| {
+                        map_column_name(column_name);
+                    });
+                }
+            });
+            return quote! {
+
+                let #ident_iter = ::polars_rows_iter::MatchingColumnsIter::<#lifetime, #field_type>::new(dataframe, #regex, #natural_order)?;
+                #claim_columns
+            };
+        }
+
+        if f.missing.is_some() {
+            let iter_type = create_iterator_type(f, &lifetime);
+            return quote! {
+
+                let column_name = columns.get(#field_name).map(String::as_str);
+                #default_column_name
+                let column = match dataframe.column(&map_column_name(column_name.unwrap_or(default_column_name))) {
+                    Err(::polars::prelude::PolarsError::ColumnNotFound(_)) => None,
+                    result => Some(result?),
+                };
+                let #ident_iter: Option<#iter_type> = match column {
+                    Some(column) => Some(Box::new(<#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::create_iter(column)?)),
+                    None => None,
+                };
+                let #ident_dtype = column.map_or(::polars::prelude::DataType::Null, |column| column.dtype().clone());
+            };
+        }
+
         quote! {
 
-            let column_name = columns.remove(#field_name);
-            let column_name = column_name.as_deref();
+            let column_name = columns.get(#field_name).map(String::as_str);
             #default_column_name
-            let column = dataframe.column(column_name.unwrap_or(default_column_name))?;
+            let column = dataframe.column(&map_column_name(column_name.unwrap_or(default_column_name)))?;
             let #ident_iter = Box::new(<#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::create_iter(column)?);
             let #ident_dtype = column.dtype().clone();
         }
     });
 
+    // Every column lookup goes through `map_column_name`, including the ones of flattened structs
+    let claimed_column_names_create = ctx.has_rest_fields().then(|| {
+        quote! {
+            let claimed_column_names = ::std::cell::RefCell::new(Vec::<String>::new());
+            let map_column_name = &|column_name: &str| {
+                let column_name = map_column_name(column_name);
+                claimed_column_names.borrow_mut().push(column_name.clone());
+                column_name
+            };
+        }
+    });
+
+    let rest_iter_create_list = ctx.fields_list.iter().filter(|f| f.rest).map(|f| {
+        let ident_iter = &f.iter_ident;
+        quote! {
+            let #ident_iter = ::polars_rows_iter::RestColumnsIter::new(dataframe, &claimed_column_names.borrow());
+        }
+    });
+
+    let remaining_rows_create = ctx
+        .counts_rows()
+        .then(|| quote! { let remaining_rows = dataframe.height(); });
+
+    let row_index_create = ctx.has_row_index().then(|| {
+        let offset = ctx
+            .attributes
+            .row_index_offset
+            .as_ref()
+            .map_or_else(|| quote! { 0 }, |offset| quote! { #offset });
+        quote! { let row_index: usize = #offset; }
+    });
+
     let struct_generics = create_struct_generics(ctx, ctx.has_lifetime.then_some(&lifetime));
     let where_clause = create_where_clause(ctx, &lifetime, true);
 
     let struct_ident = &ctx.struct_ident;
     let iter_struct_ident = &ctx.iter_struct_ident;
-    let iter_ident_list = ctx.fields_list.iter().map(|f| {
-        let ident_iter = &f.iter_ident;
-        let ident_dtype = &f.dtype_ident;
-        quote! { #ident_iter, #ident_dtype }
-    });
+    let tag_iter_create = create_tag_iter_create(ctx);
+
+    let iter_ident_list = ctx
+        .variants
+        .iter()
+        .map(|_| quote! { tag_iter })
+        .chain(ctx.fields_list.iter().map(|f| {
+            let ident_iter = &f.iter_ident;
+            let ident_dtype = &f.dtype_ident;
+            match f.has_single_column() {
+                true => quote! { #ident_iter, #ident_dtype },
+                false => quote! { #ident_iter },
+            }
+        }))
+        .chain(
+            ctx.counts_rows()
+                .then(|| quote! { remaining_rows, dataframe_lifetime: ::std::marker::PhantomData }),
+        )
+        .chain(ctx.has_row_index().then(|| quote! { row_index }));
 
     // let struct_ident = match ctx.has_lifetime {
     //     true => quote! { #struct_ident<#lifetime> },
@@ -286,22 +429,68 @@ fn create_from_dataframe_row_trait_impl(ctx: &Context) -> proc_macro2::TokenStre
 
     let builder_struct_ident = &ctx.builder_struct_ident;
 
+    let create_iter = quote! {
+        use ::polars_rows_iter::convert_case::{Case, Casing};
+
+        #claimed_column_names_create
+        #tag_iter_create
+        #(#iter_create_list)*
+        #(#rest_iter_create_list)*
+        #remaining_rows_create
+        #row_index_create
+
+        Ok(Box::new(#iter_struct_ident { #(#iter_ident_list,)* }))
+    };
+
+    // The regex of matching columns can't follow the column name transformations of an outer struct, and the
+    // columns of the outer struct can't be claimed by a rest field, so structs with these fields can't be flattened
+    // and read their columns as they are
+    let (from_dataframe_body, from_dataframe_mapped_impl) = match ctx.can_be_flattened() {
+        true => (
+            quote! {
+                <Self as ::polars_rows_iter::FromDataFrameRowMapped #lifetime_generics>::from_dataframe_mapped(
+                    dataframe,
+                    columns,
+                    &|column_name| column_name.to_string(),
+                )
+            },
+            Some(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::polars_rows_iter::FromDataFrameRowMapped #lifetime_generics for #struct_ident #struct_generics #where_clause{
+                    fn from_dataframe_mapped(
+                        dataframe: & #lifetime ::polars::prelude::DataFrame,
+                        columns: std::collections::HashMap<&'static str, String>,
+                        map_column_name: &dyn Fn(&str) -> String,
+                    ) -> ::polars::prelude::PolarsResult<Box<dyn Iterator<Item = ::polars::prelude::PolarsResult<Self>> + #lifetime>>
+                        where
+                            Self: Sized
+                    {
+                        #create_iter
+                    }
+                }
+            }),
+        ),
+        false => (
+            quote! {
+                let map_column_name = &|column_name: &str| column_name.to_string();
+                #create_iter
+            },
+            None,
+        ),
+    };
+
     quote::quote! {
         #[automatically_derived]
         impl #impl_generics ::polars_rows_iter::FromDataFrameRow #lifetime_generics for #struct_ident #struct_generics #where_clause{
             type Builder = #builder_struct_ident;
             fn from_dataframe(
                 dataframe: & #lifetime ::polars::prelude::DataFrame,
-                mut columns: std::collections::HashMap<&'static str, String>
+                columns: std::collections::HashMap<&'static str, String>,
             ) -> ::polars::prelude::PolarsResult<Box<dyn Iterator<Item = ::polars::prelude::PolarsResult<Self>> + #lifetime>>
                 where
                     Self: Sized
             {
-                use ::polars_rows_iter::convert_case::{Case, Casing};
-
-                #(#iter_create_list)*
-
-                Ok(Box::new(#iter_struct_ident { #(#iter_ident_list,)* }))
+                #from_dataframe_body
             }
 
             fn create_builder() -> #builder_struct_ident {
@@ -310,59 +499,279 @@ fn create_from_dataframe_row_trait_impl(ctx: &Context) -> proc_macro2::TokenStre
                 }
             }
         }
+
+        #from_dataframe_mapped_impl
     }
 }
 
-#[derive(Debug, deluxe::ExtractAttributes)]
-#[deluxe(attributes(column))]
-struct ColumnFieldAttributes(#[deluxe(flatten)] Vec<syn::Expr>);
+fn create_tag_iter_create(ctx: &Context) -> Option<proc_macro2::TokenStream> {
+    ctx.variants.as_ref()?;
+    let tag = ctx.attributes.tag.as_ref()?;
 
-fn create_iterator_struct_field_info(mut field: Field) -> syn::Result<FieldInfo> {
-    let ident = match &field.ident {
-        Some(ident) => ident.clone(),
-        None => {
-            return Err(syn::Error::new_spanned(
-                &field,
-                "FromDataFrameRow requires named fields (tuple structs not supported)",
-            ))
+    Some(quote! {
+        let column = dataframe.column(&map_column_name(#tag))?;
+        let tag_iter = Box::new(<&str as ::polars_rows_iter::IterFromColumn>::create_iter(column)?);
+    })
+}
+
+/// Splits the fields into the ones read from columns and the ones with `#[column(skip)]`.
+fn create_field_infos(
+    fields: &syn::Fields,
+    variant_ident: Option<&Ident>,
+    lossy_cast: bool,
+    fields_list: &mut Vec<FieldInfo>,
+    skipped_fields: &mut Vec<SkippedField>,
+) -> syn::Result<()> {
+    let mut has_rest_field = false;
+
+    for field in fields.iter().cloned() {
+        let attrs = ColumnAttribute::from_field(&field)?;
+        let columns = ColumnsAttribute::from_field(&field)?;
+        if attrs.rest {
+            if has_rest_field {
+                return Err(syn::Error::new_spanned(
+                    &field,
+                    "only one field can have #[column(rest)]",
+                ));
+            }
+            has_rest_field = true;
         }
+        if is_row_index_field(&field)? {
+            skipped_fields.push(SkippedField {
+                ident: get_field_ident(&field)?,
+                default: None,
+                row_index: true,
+            });
+        } else if attrs.skip {
+            skipped_fields.push(SkippedField {
+                ident: get_field_ident(&field)?,
+                default: attrs.default,
+                row_index: false,
+            });
+        } else {
+            fields_list.push(create_iterator_struct_field_info(
+                field,
+                attrs,
+                columns,
+                variant_ident,
+                lossy_cast,
+            )?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Fields with `#[row_index]` are set to the position of the row and can't have any other attribute.
+fn is_row_index_field(field: &Field) -> syn::Result<bool> {
+    let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("row_index")) else {
+        return Ok(false);
+    };
+    attr.meta.require_path_only()?;
+
+    if field
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("column") || attr.path().is_ident("columns"))
+    {
+        let field_name = get_field_ident(field)?;
+        return Err(syn::Error::new_spanned(
+            field,
+            format!("field '{field_name}' is a row index, so it can't be read from a column"),
+        ));
+    }
+
+    Ok(true)
+}
+
+fn get_field_ident(field: &Field) -> syn::Result<Ident> {
+    field.ident.clone().ok_or_else(|| {
+        syn::Error::new_spanned(
+            field,
+            "FromDataFrameRow requires named fields (tuple structs not supported)",
+        )
+    })
+}
+
+fn create_iterator_struct_field_info(
+    field: Field,
+    mut attrs: ColumnAttribute,
+    columns: Option<ColumnsAttribute>,
+    variant_ident: Option<&Ident>,
+    lossy_cast: bool,
+) -> syn::Result<FieldInfo> {
+    let ident = get_field_ident(&field)?;
+    // Fields of different variants can share a name, so the iterator idents are made unique per variant
+    let name = match variant_ident {
+        Some(variant_ident) => format!("{}_{ident}", variant_ident.to_string().to_case(Case::Snake)),
+        None => ident.to_string(),
     };
-    let name = ident.to_string();
 
     let iter_ident = Ident::new(format!("{name}_iter").as_str(), Span::call_site());
     let dtype_ident = Ident::new(format!("{name}_dtype").as_str(), Span::call_site());
-    let ty = field.ty.clone();
 
-    let attrs: ColumnFieldAttributes = deluxe::extract_attributes(&mut field)?;
+    // Fields read from all matching columns are read like a field of the element type from each column
+    let (ty, columns) = match columns {
+        Some(attribute) => {
+            let (element_ty, is_map) = get_matching_columns_element_type(&field.ty).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &field.ty,
+                    format!(
+                        "field '{ident}' has #[columns(...)], so its type has to be Vec<T>, HashMap<String, T> or BTreeMap<String, T>"
+                    ),
+                )
+            })?;
+            (element_ty, Some(MatchingColumns { attribute, is_map }))
+        }
+        None => (field.ty.clone(), None),
+    };
 
-    let column_name_expr = match attrs.0.len() {
-        0 => Expr::Lit(ExprLit {
-            attrs: vec![],
-            lit: syn::Lit::Str(LitStr::new(&name, field.span())),
+    // Flattened fields transform the column names of the flattened struct, which are only known at runtime
+    let column_name_expr = match (&attrs.flatten, &columns) {
+        (Some(_), _) => syn::parse_quote!(flattened_column_name),
+        (None, Some(columns)) => columns.attribute.regex.clone(),
+        (None, None) => attrs.name.unwrap_or_else(|| {
+            Expr::Lit(ExprLit {
+                attrs: vec![],
+                lit: syn::Lit::Str(LitStr::new(&ident.to_string(), field.span())),
+            })
         }),
-        1 => attrs.0[0].clone(),
-        _ => {
-            return Err(syn::Error::new_spanned(
-                &field,
-                format!("field '{}' can have only one #[column(...)] attribute", name),
-            ))
-        }
     };
 
     let mut is_optional = false;
     let inner_ty = get_inner_type_from_options(ty.clone(), &mut is_optional);
 
+    if is_optional && attrs.default.is_some() {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            format!("field '{ident}' has a default, so its type can't be Option<...>"),
+        ));
+    }
+
+    if is_optional && attrs.flatten.is_some() {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            format!("field '{ident}' is flattened, so its type can't be Option<...>"),
+        ));
+    }
+
+    if let (false, Some(MissingValue::None)) = (is_optional, &attrs.missing) {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            format!("field '{ident}' has missing = \"none\", so its type has to be Option<...>"),
+        ));
+    }
+
+    if lossy_cast && attrs.conversion.is_none() && columns.is_none() && !attrs.rest && is_numeric_primitive(&inner_ty) {
+        attrs.conversion = Some(Conversion::Cast);
+    }
+
+    // Converted fields are read as their source type, parsed fields as strings
+    let source_ty = match &attrs.conversion {
+        Some(Conversion::FromStr | Conversion::Datetime(_)) => syn::parse_quote!(&'a str),
+        Some(Conversion::With { source_ty, .. }) => get_inner_type_from_options(source_ty.as_ref().clone(), &mut false),
+        Some(Conversion::Cast) => syn::parse_quote!(::polars_rows_iter::Cast<#inner_ty>),
+        None => inner_ty.clone(),
+    };
+
     Ok(FieldInfo {
         name,
         ident,
         iter_ident,
         dtype_ident,
         inner_ty,
+        source_ty,
         is_optional,
         column_name_expr,
+        conversion: attrs.conversion,
+        default: attrs.default,
+        missing: attrs.missing,
+        flatten: attrs.flatten,
+        columns,
+        rest: attrs.rest,
     })
 }
 
+fn create_variant_infos(
+    data_enum: &syn::DataEnum,
+    lossy_cast: bool,
+) -> syn::Result<(Vec<FieldInfo>, Vec<SkippedField>, Vec<VariantInfo>)> {
+    let mut fields_list = Vec::new();
+    let mut skipped_fields = Vec::new();
+    let mut variants = Vec::new();
+
+    for variant in &data_enum.variants {
+        if let syn::Fields::Unnamed(fields) = &variant.fields {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "FromDataFrameRow requires named fields (tuple variants not supported)",
+            ));
+        }
+
+        let attributes = FromDataFrameVariantAttribute::from_variant(variant)?;
+        let tag_value = match attributes.rename {
+            Some(rename) => rename.value(),
+            None => variant.ident.to_string(),
+        };
+
+        let start = fields_list.len();
+        let skipped_start = skipped_fields.len();
+        create_field_infos(
+            &variant.fields,
+            Some(&variant.ident),
+            lossy_cast,
+            &mut fields_list,
+            &mut skipped_fields,
+        )?;
+
+        variants.push(VariantInfo {
+            ident: variant.ident.clone(),
+            tag_value,
+            fields: start..fields_list.len(),
+            skipped_fields: skipped_start..skipped_fields.len(),
+        });
+    }
+
+    Ok((fields_list, skipped_fields, variants))
+}
+
+/// The fields `#[from_dataframe(cast = "lossy")]` applies to, 128 bit integers are only cast with `#[column(cast)]`.
+fn is_numeric_primitive(ty: &Type) -> bool {
+    const NUMERIC_PRIMITIVES: &[&str] = &["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "f32", "f64"];
+
+    match ty {
+        Type::Path(type_path) => NUMERIC_PRIMITIVES.iter().any(|name| type_path.path.is_ident(name)),
+        _ => false,
+    }
+}
+
+/// The element type of a `Vec<T>`, `HashMap<String, T>` or `BTreeMap<String, T>` field and whether it's a map.
+fn get_matching_columns_element_type(ty: &Type) -> Option<(Type, bool)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let is_map = match segment.ident.to_string().as_str() {
+        "Vec" => false,
+        "HashMap" | "BTreeMap" => true,
+        _ => return None,
+    };
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    let mut type_arguments = arguments.args.iter().filter_map(|argument| match argument {
+        GenericArgument::Type(ty) => Some(ty.clone()),
+        _ => None,
+    });
+    if is_map {
+        // The key type
+        type_arguments.next()?;
+    }
+
+    type_arguments.next().map(|element_ty| (element_ty, is_map))
+}
+
 fn try_get_inner_option_type(ty: &Type) -> Option<Type> {
     if let Type::Path(type_path) = ty {
         let segment = type_path.path.segments.first()?;
@@ -379,7 +788,7 @@ fn try_get_inner_option_type(ty: &Type) -> Option<Type> {
     None
 }
 
-fn get_inner_type_from_options(ty: Type, is_optional: &mut bool) -> Type {
+pub fn get_inner_type_from_options(ty: Type, is_optional: &mut bool) -> Type {
     if let Some(inner) = try_get_inner_option_type(&ty) {
         *is_optional = true;
         get_inner_type_from_options(inner, is_optional)
@@ -388,12 +797,51 @@ fn get_inner_type_from_options(ty: Type, is_optional: &mut bool) -> Type {
     }
 }
 
+/// Flattened fields are read by the row iterator of the flattened struct, all others by `IterFromColumn`.
+fn create_iterator_item_type(field_info: &FieldInfo, lifetime: &LifetimeParam) -> proc_macro2::TokenStream {
+    if field_info.rest {
+        return quote! { Vec<(&#lifetime str, ::polars::prelude::AnyValue<#lifetime>)> };
+    }
+
+    let ty = coerce_lifetime(field_info.source_ty.clone(), lifetime);
+    match (&field_info.flatten, &field_info.columns) {
+        (Some(_), _) => quote! { ::polars::prelude::PolarsResult<#ty> },
+        (None, Some(_)) => quote! { Vec<Option<<#ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::RawInner>> },
+        (None, None) => quote! { Option<<#ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::RawInner> },
+    }
+}
+
+fn create_iterator_type(field_info: &FieldInfo, lifetime: &LifetimeParam) -> proc_macro2::TokenStream {
+    let item_type = create_iterator_item_type(field_info, lifetime);
+    quote! { Box<dyn Iterator<Item = #item_type> + #lifetime> }
+}
+
 fn create_iterator_struct_field(field_info: &FieldInfo, lifetime: &LifetimeParam) -> proc_macro2::TokenStream {
     let ident = &field_info.iter_ident;
     let dtype_ident = &field_info.dtype_ident;
-    let ty = coerce_lifetime(field_info.inner_ty.clone(), lifetime);
+    let iter_type = create_iterator_type(field_info, lifetime);
+
+    if field_info.flatten.is_some() {
+        return quote! { #ident : #iter_type, };
+    }
+
+    if field_info.rest {
+        return quote! { #ident : ::polars_rows_iter::RestColumnsIter<#lifetime>, };
+    }
+
+    if field_info.columns.is_some() {
+        let ty = coerce_lifetime(field_info.source_ty.clone(), lifetime);
+        return quote! { #ident : ::polars_rows_iter::MatchingColumnsIter<#lifetime, #ty>, };
+    }
+
+    // Fields for missing columns have no iterator
+    let iter_type = match field_info.missing {
+        Some(_) => quote! { Option<#iter_type> },
+        None => iter_type,
+    };
+
     quote! {
-        #ident : Box<dyn Iterator<Item = Option<<#ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::RawInner>> + #lifetime>,
+        #ident : #iter_type,
         #dtype_ident: ::polars::prelude::DataType,
     }
 }
@@ -406,6 +854,23 @@ fn create_iterator_struct(ctx: &Context) -> proc_macro2::TokenStream {
         .iter()
         .map(|field_info| create_iterator_struct_field(field_info, &lifetime));
 
+    let tag_field = ctx.variants.as_ref().map(|_| {
+        quote! {
+            tag_iter: Box<dyn Iterator<Item = Option<&#lifetime str>> + #lifetime>,
+        }
+    });
+
+    // The lifetime might not be used by any other field
+    let remaining_rows_field = ctx.counts_rows().then(|| {
+        quote! {
+            remaining_rows: usize,
+            dataframe_lifetime: ::std::marker::PhantomData<&#lifetime ()>,
+        }
+    });
+
+    // The position of the next row, including the `row_index_offset`
+    let row_index_field = ctx.has_row_index().then(|| quote! { row_index: usize, });
+
     let iter_struct_ident = &ctx.iter_struct_ident;
 
     let struct_generics = create_struct_generics(ctx, Some(&lifetime));
@@ -414,7 +879,10 @@ fn create_iterator_struct(ctx: &Context) -> proc_macro2::TokenStream {
     quote! {
         #[automatically_derived]
         struct #iter_struct_ident #struct_generics #where_clause {
+            #tag_field
             #(#fields)*
+            #remaining_rows_field
+            #row_index_field
         }
     }
 }
@@ -422,25 +890,66 @@ fn create_iterator_struct(ctx: &Context) -> proc_macro2::TokenStream {
 fn create_iterator_struct_impl(ctx: &Context) -> proc_macro2::TokenStream {
     let lifetime = create_lifetime_param("a");
 
-    let fn_params = ctx.fields_list.iter().map(|field_info| {
-        let ident = &field_info.ident;
-        let field_type = coerce_lifetime(field_info.inner_ty.clone(), &lifetime);
-        quote! { #ident: Option<<#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::RawInner> }
-    });
+    let row_index_param = ctx.has_row_index().then(|| quote! { row_index: usize, });
 
-    let assignments = ctx.fields_list.iter().map(|field_info| {
-        let ident = &field_info.ident;
-        let ident_dtype = &field_info.dtype_ident;
-        let field_type = coerce_lifetime(field_info.inner_ty.clone(), &lifetime);
-        let column_name = &field_info.column_name_expr;
+    let tag_param = ctx
+        .variants
+        .as_ref()
+        .map(|_| quote! { tag_value: Option<&#lifetime str>, });
 
-        match field_info.is_optional {
-            true => quote! { #ident: <Option<#field_type> as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#ident, #column_name, &self.#ident_dtype)? },
-            false => quote! { #ident: <#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#ident, #column_name, &self.#ident_dtype)? },
+    let fn_params = ctx.fields_list.iter().map(|field_info| {
+        let value_ident = field_info.value_ident();
+        let value_type = create_iterator_item_type(field_info, &lifetime);
+        match field_info.missing {
+            // None if the column is missing
+            Some(_) => quote! { #value_ident: Option<#value_type> },
+            None => quote! { #value_ident: #value_type },
         }
     });
 
     let struct_ident = &ctx.struct_ident;
+
+    let body = match &ctx.variants {
+        None => {
+            let assignments = create_field_assignments(&ctx.fields_list, &ctx.skipped_fields, &lifetime);
+            quote! {
+                Ok(#struct_ident {
+                    #(#assignments,)*
+                })
+            }
+        }
+        Some(variants) => {
+            let tag = &ctx.attributes.tag;
+            let variant_arms = variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let tag_value = &variant.tag_value;
+                let assignments = create_field_assignments(
+                    &ctx.fields_list[variant.fields.clone()],
+                    &ctx.skipped_fields[variant.skipped_fields.clone()],
+                    &lifetime,
+                );
+                quote! {
+                    #tag_value => Ok(#struct_ident::#variant_ident {
+                        #(#assignments,)*
+                    }),
+                }
+            });
+
+            quote! {
+                let tag_column_name = #tag;
+                let tag_value = tag_value.ok_or_else(|| {
+                    <&str as ::polars_rows_iter::IterFromColumn<#lifetime>>::unexpected_null_value_error(tag_column_name)
+                })?;
+
+                match tag_value {
+                    #(#variant_arms)*
+                    tag_value => Err(::polars::prelude::PolarsError::ComputeError(
+                        format!("Value '{tag_value}' in tag column '{tag_column_name}' does not match any variant of {}", stringify!(#struct_ident)).into(),
+                    )),
+                }
+            }
+        }
+    };
     let iter_struct_ident = &ctx.iter_struct_ident;
 
     let impl_generics = create_impl_generics(ctx, &lifetime);
@@ -454,14 +963,179 @@ fn create_iterator_struct_impl(ctx: &Context) -> proc_macro2::TokenStream {
             #[allow(clippy::too_many_arguments)]
             fn create(
                 &self,
+                #row_index_param
+                #tag_param
                 #(#fn_params,)*
             ) -> ::polars::prelude::PolarsResult<#struct_ident #type_generics> {
+                #body
+            }
+        }
+    }
+}
 
-                Ok(#struct_ident {
-                    #(#assignments,)*
-                })
+fn create_field_assignments<'f>(
+    fields_list: &'f [FieldInfo],
+    skipped_fields: &'f [SkippedField],
+    lifetime: &'f LifetimeParam,
+) -> impl Iterator<Item = proc_macro2::TokenStream> + 'f {
+    let skipped_assignments = skipped_fields.iter().map(|skipped_field| {
+        let ident = &skipped_field.ident;
+        if skipped_field.row_index {
+            return quote! {
+                #ident: ::std::convert::TryFrom::try_from(row_index).map_err(|_| {
+                    ::polars::prelude::PolarsError::ComputeError(
+                        format!("Row index {row_index} is out of range for field '{}'", stringify!(#ident)).into(),
+                    )
+                })?
+            };
+        }
+        match &skipped_field.default {
+            Some(DefaultValue::Expr(default)) => quote! { #ident: #default },
+            _ => quote! { #ident: Default::default() },
+        }
+    });
+
+    fields_list
+        .iter()
+        .map(move |field_info| {
+            let ident = &field_info.ident;
+
+            if field_info.flatten.is_some() {
+                let value_ident = field_info.value_ident();
+                return quote! { #ident: #value_ident? };
+            }
+
+            if let Some(columns) = &field_info.columns {
+                return create_matching_columns_assignment(field_info, columns, lifetime);
+            }
+
+            if field_info.rest {
+                let value_ident = field_info.value_ident();
+                return quote! {
+                    #ident: #value_ident.into_iter().map(|(column_name, value)| (column_name.into(), value)).collect()
+                };
+            }
+
+            // Fields with a default are read like optional fields and the null is replaced afterwards
+            let value = match &field_info.default {
+                Some(DefaultValue::Trait) => {
+                    let value = create_field_value(field_info, lifetime, true);
+                    quote! { #value.unwrap_or_default() }
+                }
+                Some(DefaultValue::Expr(default)) => {
+                    let value = create_field_value(field_info, lifetime, true);
+                    quote! { #value.unwrap_or_else(|| #default) }
+                }
+                None => create_field_value(field_info, lifetime, field_info.is_optional),
+            };
 
+            let missing_value = match (&field_info.missing, &field_info.default) {
+                (None, _) => return quote! { #ident: #value },
+                (Some(MissingValue::None), _) => quote! { None },
+                (Some(MissingValue::Default), Some(DefaultValue::Expr(default))) => quote! { #default },
+                (Some(MissingValue::Default), _) => quote! { Default::default() },
+            };
+
+            let value_ident = field_info.value_ident();
+            quote! {
+                #ident: match #value_ident {
+                    Some(#value_ident) => #value,
+                    None => #missing_value,
+                }
             }
+        })
+        .chain(skipped_assignments)
+}
+
+fn create_matching_columns_assignment(
+    field_info: &FieldInfo,
+    columns: &MatchingColumns,
+    lifetime: &LifetimeParam,
+) -> proc_macro2::TokenStream {
+    let ident = &field_info.ident;
+    let iter_ident = &field_info.iter_ident;
+    let value_ident = field_info.value_ident();
+    let field_type = coerce_lifetime(field_info.inner_ty.clone(), lifetime);
+
+    let value_type = match field_info.is_optional {
+        true => quote! { Option<#field_type> },
+        false => quote! { #field_type },
+    };
+
+    let values = quote! { self.#iter_ident.get_values::<#value_type>(#value_ident)?.into_iter() };
+
+    match columns.is_map {
+        true => quote! { #ident: #values.map(|(column_name, value)| (column_name.into(), value)).collect() },
+        false => quote! { #ident: #values.map(|(_, value)| value).collect() },
+    }
+}
+
+fn create_field_value(field_info: &FieldInfo, lifetime: &LifetimeParam, is_optional: bool) -> proc_macro2::TokenStream {
+    let value_ident = field_info.value_ident();
+    let ident_dtype = &field_info.dtype_ident;
+    let field_type = coerce_lifetime(field_info.inner_ty.clone(), lifetime);
+    let column_name = &field_info.column_name_expr;
+
+    if let Some(Conversion::With { function, .. }) = &field_info.conversion {
+        let source_ty = coerce_lifetime(field_info.source_ty.clone(), lifetime);
+
+        return match (field_info.is_source_optional(), is_optional) {
+            (true, _) => quote! {
+                #function(<Option<#source_ty> as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)?)?
+            },
+            (false, true) => quote! {
+                <Option<#source_ty> as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)?
+                    .map(#function)
+                    .transpose()?
+            },
+            (false, false) => quote! {
+                #function(<#source_ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)?)?
+            },
+        };
+    }
+
+    if let Some(Conversion::Cast) = &field_info.conversion {
+        let source_ty = &field_info.source_ty;
+        return match is_optional {
+            true => quote! {
+                <Option<#source_ty> as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)?
+                    .map(|value| value.0)
+            },
+            false => quote! {
+                <#source_ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)?.0
+            },
+        };
+    }
+
+    if let Some(conversion) = &field_info.conversion {
+        let parse_value = match conversion {
+            Conversion::Datetime(format) => {
+                quote! { ::polars_rows_iter::parse_column_datetime::<#field_type>(value, #format, #column_name) }
+            }
+            _ => quote! { ::polars_rows_iter::parse_column_value::<#field_type>(value, #column_name) },
+        };
+
+        return match is_optional {
+            true => quote! {
+                <Option<&#lifetime str> as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)?
+                    .map(|value| #parse_value)
+                    .transpose()?
+            },
+            false => quote! {
+                {
+                    let value = <&#lifetime str as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)?;
+                    #parse_value?
+                }
+            },
+        };
+    }
+
+    match is_optional {
+        true => {
+            quote! { <Option<#field_type> as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)? }
+        }
+        false => {
+            quote! { <#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(#value_ident, #column_name, &self.#ident_dtype)? }
         }
     }
 }
@@ -492,22 +1166,61 @@ fn create_iterator_impl_for_iterator_struct(ctx: &Context) -> proc_macro2::Token
     let fields: Vec<_> = ctx
         .fields_list
         .iter()
-        .map(|f| {
-            (
-                Ident::new(
-                    format!("{field_name}_value", field_name = f.name).as_str(),
-                    Span::call_site(),
-                ),
-                &f.iter_ident,
-            )
-        })
+        .filter(|f| f.missing.is_none())
+        .map(|f| (f.value_ident(), &f.iter_ident))
         .collect();
 
-    let next_value_list = fields.iter().map(|(value_ident, iter_ident)| {
-        quote! { let #value_ident = self.#iter_ident.next()? }
+    let missing_next_value_list = ctx.fields_list.iter().filter(|f| f.missing.is_some()).map(|f| {
+        let value_ident = f.value_ident();
+        let iter_ident = &f.iter_ident;
+        quote! {
+            let #value_ident = match &mut self.#iter_ident {
+                Some(iter) => Some(iter.next()?),
+                None => None,
+            }
+        }
     });
 
-    let value_ident_list = fields.iter().map(|(value_ident, _)| value_ident);
+    let remaining_rows_next = ctx.counts_rows().then(|| {
+        quote! {
+            if self.remaining_rows == 0 {
+                return None;
+            }
+            self.remaining_rows -= 1;
+        }
+    });
+
+    let row_index_next = ctx.has_row_index().then(|| {
+        quote! {
+            let row_index = self.row_index;
+            self.row_index += 1;
+        }
+    });
+
+    let row_index = ctx.has_row_index().then(|| Ident::new("row_index", Span::call_site()));
+
+    let tag_value = ctx
+        .variants
+        .as_ref()
+        .map(|_| Ident::new("tag_value", Span::call_site()));
+    let tag_iter = ctx.variants.as_ref().map(|_| Ident::new("tag_iter", Span::call_site()));
+
+    let next_value_list = tag_value
+        .iter()
+        .zip(tag_iter.iter())
+        .chain(
+            fields
+                .iter()
+                .map(|(value_ident, iter_ident)| (value_ident, *iter_ident)),
+        )
+        .map(|(value_ident, iter_ident)| {
+            quote! { let #value_ident = self.#iter_ident.next()? }
+        });
+
+    let value_ident_list = row_index
+        .into_iter()
+        .chain(tag_value.iter().cloned())
+        .chain(ctx.fields_list.iter().map(|f| f.value_ident()));
 
     let struct_ident = &ctx.struct_ident;
     let iter_struct_ident = &ctx.iter_struct_ident;
@@ -522,7 +1235,10 @@ fn create_iterator_impl_for_iterator_struct(ctx: &Context) -> proc_macro2::Token
             type Item = ::polars::prelude::PolarsResult<#struct_ident #type_generics>;
 
             fn next(&mut self) -> Option<Self::Item> {
+                #remaining_rows_next
                 #(#next_value_list;)*
+                #(#missing_next_value_list;)*
+                #row_index_next
 
                 Some(self.create(#(#value_ident_list,)*))
             }
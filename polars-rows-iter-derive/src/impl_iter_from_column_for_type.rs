@@ -5,7 +5,7 @@ pub fn create_impl_for(ident: syn::Ident) -> proc_macro::TokenStream {
         impl<'a> IterFromColumn<'a> for #ident {
             type RawInner = #ident;
             fn create_iter(column: &'a polars::prelude::Column) -> polars::prelude::PolarsResult<impl Iterator<Item = Option<#ident>> + 'a> {
-                Ok(column.#ident()?.iter())
+                create_primitive_iter(column, |column| Ok(column.#ident()?.iter()))
             }
 
             #[inline]
@@ -20,8 +20,7 @@ pub fn create_impl_for(ident: syn::Ident) -> proc_macro::TokenStream {
         impl<'a> IterFromColumn<'a> for Option<#ident> {
             type RawInner = #ident;
             fn create_iter(column: &'a polars::prelude::Column) -> polars::prelude::PolarsResult<impl Iterator<Item = Option<#ident>> + 'a> {
-                let iter = column.#ident()?.iter();
-                Ok(iter)
+                create_primitive_iter(column, |column| Ok(column.#ident()?.iter()))
             }
 
             #[inline]
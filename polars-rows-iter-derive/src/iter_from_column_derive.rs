@@ -0,0 +1,135 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, DeriveInput, GenericParam, Lifetime, LifetimeParam};
+
+/// Implements `IterFromColumn` for single field structs by delegating to the type of the field.
+pub fn iter_from_column_derive_impl(ast: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &ast.data {
+        syn::Data::Struct(data_struct) => &data_struct.fields,
+        syn::Data::Enum(e) => {
+            return Err(syn::Error::new_spanned(
+                e.enum_token,
+                "IterFromColumn can only be derived for structs with a single field, use FromColumnValue for enums",
+            ))
+        }
+        syn::Data::Union(u) => {
+            return Err(syn::Error::new_spanned(
+                u.union_token,
+                "IterFromColumn cannot be derived for unions",
+            ))
+        }
+    };
+
+    let field = match fields.len() {
+        1 => fields.iter().next().unwrap(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "IterFromColumn can only be derived for structs with a single field",
+            ))
+        }
+    };
+
+    let mut lifetimes = ast.generics.lifetimes();
+    let lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (Some(lifetime), None) => lifetime.lifetime.clone(),
+        (None, _) => Lifetime::new("'a", Span::call_site()),
+        (Some(_), Some(extra_lifetime)) => {
+            return Err(syn::Error::new_spanned(
+                extra_lifetime,
+                "IterFromColumn only supports a single lifetime parameter",
+            ))
+        }
+    };
+
+    let struct_ident = &ast.ident;
+    let inner_ty = &field.ty;
+
+    let construct = match &field.ident {
+        Some(field_ident) => quote! { |value| #struct_ident { #field_ident: value } },
+        None => quote! { #struct_ident },
+    };
+
+    let mut impl_generics = ast.generics.clone();
+    if ast.generics.lifetimes().next().is_none() {
+        impl_generics
+            .params
+            .insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    }
+    impl_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { #inner_ty: ::polars_rows_iter::IterFromColumn<#lifetime> });
+
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+    let (nullable_impl_generics, type_generics, nullable_where_clause) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::polars_rows_iter::IterFromColumn<#lifetime> for #struct_ident #type_generics #where_clause {
+            type RawInner = <#inner_ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::RawInner;
+            fn create_iter(
+                column: &#lifetime ::polars::prelude::Column,
+            ) -> ::polars::prelude::PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + #lifetime> {
+                <#inner_ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::create_iter(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                column_name: &str,
+                dtype: &::polars::prelude::DataType,
+            ) -> ::polars::prelude::PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                <#inner_ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_value(polars_value, column_name, dtype)
+                    .map(#construct)
+            }
+        }
+
+        #[automatically_derived]
+        impl #nullable_impl_generics ::polars_rows_iter::NullableIterFromColumn for #struct_ident #type_generics #nullable_where_clause {}
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn struct_lifetime_is_used_for_the_impl() {
+        let result = iter_from_column_derive_impl(parse_quote! {
+            struct Email<'b>(&'b str);
+        })
+        .unwrap()
+        .to_string();
+
+        assert!(result.contains("impl < 'b > :: polars_rows_iter :: IterFromColumn < 'b > for Email < 'b >"));
+    }
+
+    #[test]
+    fn lifetime_is_added_for_owned_newtypes() {
+        let result = iter_from_column_derive_impl(parse_quote! {
+            struct UserId(i64);
+        })
+        .unwrap()
+        .to_string();
+
+        assert!(result.contains("impl < 'a > :: polars_rows_iter :: IterFromColumn < 'a > for UserId"));
+        assert!(result.contains("where i64 : :: polars_rows_iter :: IterFromColumn < 'a >"));
+    }
+
+    #[test]
+    fn structs_with_several_fields_are_rejected() {
+        let err = iter_from_column_derive_impl(parse_quote! {
+            struct Point(f64, f64);
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "IterFromColumn can only be derived for structs with a single field"
+        );
+    }
+}
@@ -2,14 +2,18 @@
 //!
 //! This crate exports the macros required by the main polars-rows-iter crate.
 
+mod column_attribute;
+mod columns_attribute;
 mod context;
 mod field_info;
+mod from_column_value_derive;
 mod from_dataframe_attribute;
 mod from_dataframe_row_derive;
 mod impl_iter_from_column_for_type;
+mod iter_from_column_derive;
 mod tuple_iterators;
 
-#[proc_macro_derive(FromDataFrameRow, attributes(column, from_dataframe))]
+#[proc_macro_derive(FromDataFrameRow, attributes(column, columns, row_index, from_dataframe))]
 pub fn from_dataframe_row_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast: syn::DeriveInput = match syn::parse2(input.into()) {
         Ok(ast) => ast,
@@ -20,6 +24,28 @@ pub fn from_dataframe_row_derive_macro(input: proc_macro::TokenStream) -> proc_m
         .into()
 }
 
+#[proc_macro_derive(FromColumnValue, attributes(from_column_value))]
+pub fn from_column_value_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: syn::DeriveInput = match syn::parse2(input.into()) {
+        Ok(ast) => ast,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    from_column_value_derive::from_column_value_derive_impl(ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(IterFromColumn)]
+pub fn iter_from_column_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: syn::DeriveInput = match syn::parse2(input.into()) {
+        Ok(ast) => ast,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    iter_from_column_derive::iter_from_column_derive_impl(ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro]
 pub fn iter_from_column_for_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ident: syn::Ident = match syn::parse(input) {
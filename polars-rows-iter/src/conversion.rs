@@ -0,0 +1,265 @@
+//! Conversions of column values into field types, used by the code generated for `#[column(...)]` attributes.
+
+use polars::prelude::*;
+use std::{fmt::Display, str::FromStr};
+
+/// Converts a string value with `FromStr`, used for fields with `#[column(parse)]`.
+#[doc(hidden)]
+pub fn parse_column_value<T>(value: &str, column_name: &str) -> PolarsResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|err| polars_err!(ComputeError: "Cannot parse value '{value}' in column '{column_name}': {err}"))
+}
+
+/// Date and time types that can be parsed from strings with a chrono format, see `#[column(parse_datetime = "...")]`.
+#[cfg(feature = "chrono")]
+pub trait FromDatetimeStr: Sized {
+    fn parse_from_str(value: &str, format: &str) -> chrono::ParseResult<Self>;
+}
+
+#[cfg(feature = "chrono")]
+impl FromDatetimeStr for chrono::NaiveDateTime {
+    fn parse_from_str(value: &str, format: &str) -> chrono::ParseResult<Self> {
+        chrono::NaiveDateTime::parse_from_str(value, format)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromDatetimeStr for chrono::NaiveDate {
+    fn parse_from_str(value: &str, format: &str) -> chrono::ParseResult<Self> {
+        chrono::NaiveDate::parse_from_str(value, format)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromDatetimeStr for chrono::NaiveTime {
+    fn parse_from_str(value: &str, format: &str) -> chrono::ParseResult<Self> {
+        chrono::NaiveTime::parse_from_str(value, format)
+    }
+}
+
+/// The format has to contain the offset, like `%z`.
+#[cfg(feature = "chrono")]
+impl FromDatetimeStr for chrono::DateTime<chrono::FixedOffset> {
+    fn parse_from_str(value: &str, format: &str) -> chrono::ParseResult<Self> {
+        chrono::DateTime::parse_from_str(value, format)
+    }
+}
+
+/// The format has to contain the offset, like `%z`. The parsed value is converted to UTC.
+#[cfg(feature = "chrono")]
+impl FromDatetimeStr for chrono::DateTime<chrono::Utc> {
+    fn parse_from_str(value: &str, format: &str) -> chrono::ParseResult<Self> {
+        chrono::DateTime::parse_from_str(value, format).map(|datetime| datetime.to_utc())
+    }
+}
+
+/// Converts a string value with a chrono format, used for fields with `#[column(parse_datetime = "...")]`.
+#[cfg(feature = "chrono")]
+#[doc(hidden)]
+pub fn parse_column_datetime<T: FromDatetimeStr>(value: &str, format: &str, column_name: &str) -> PolarsResult<T> {
+    T::parse_from_str(value, format).map_err(
+        |err| polars_err!(ComputeError: "Cannot parse value '{value}' in column '{column_name}' with format '{format}': {err}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use itertools::Itertools;
+    use polars::prelude::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn parse_rows_iter_test() {
+        let df = df!(
+            "id" => ["1", "2"],
+            "address" => [Some("127.0.0.1"), None],
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            #[column(parse)]
+            id: u64,
+            #[column("address", parse)]
+            ip: Option<Ipv4Addr>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    id: 1,
+                    ip: Some(Ipv4Addr::LOCALHOST)
+                },
+                TestRow { id: 2, ip: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors_name_column_and_value() {
+        let df = df!("id" => ["1", "x"]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow)]
+        struct TestRow {
+            #[column("id", parse)]
+            _id: u64,
+        }
+
+        let err_msg = df
+            .rows_iter::<TestRow>()
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            "Cannot parse value 'x' in column 'id': invalid digit found in string"
+        );
+    }
+
+    mod convert {
+        use polars::prelude::*;
+
+        #[derive(Debug, PartialEq)]
+        pub struct Cents(pub i64);
+
+        pub fn cents(value: &str) -> PolarsResult<Cents> {
+            let invalid_price = || polars_err!(ComputeError: "Invalid price '{value}'");
+            let (units, cents) = value.split_once('.').ok_or_else(invalid_price)?;
+            let units = units.parse::<i64>().map_err(|_| invalid_price())?;
+            let cents = cents.parse::<i64>().map_err(|_| invalid_price())?;
+            Ok(Cents(units * 100 + cents))
+        }
+
+        pub fn percent_or_zero(value: Option<f64>) -> PolarsResult<u8> {
+            Ok((value.unwrap_or(0.0) * 100.0).round() as u8)
+        }
+
+        pub fn is_even(value: i64) -> PolarsResult<bool> {
+            Ok(value % 2 == 0)
+        }
+    }
+
+    #[test]
+    fn with_rows_iter_test() {
+        use convert::Cents;
+
+        let df = df!(
+            "price" => ["1.99", "0.50"],
+            "discount" => [Some(0.25), None],
+            "id" => [1i64, 2],
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            #[column(with = convert::cents, from = &str)]
+            price: Cents,
+            #[column(with = convert::percent_or_zero, from = Option<f64>)]
+            discount: u8,
+            #[column("id", with = convert::is_even, from = i64)]
+            even_id: bool,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    price: Cents(199),
+                    discount: 25,
+                    even_id: false
+                },
+                TestRow {
+                    price: Cents(50),
+                    discount: 0,
+                    even_id: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn with_errors_are_row_errors() {
+        let df = df!("price" => ["1.99", "free"]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow)]
+        struct TestRow {
+            #[column("price", with = convert::cents, from = &str)]
+            _price: convert::Cents,
+        }
+
+        let mut rows = df.rows_iter::<TestRow>().unwrap();
+
+        assert!(rows.next().unwrap().is_ok());
+        assert_eq!(rows.next().unwrap().unwrap_err().to_string(), "Invalid price 'free'");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn parse_datetime_rows_iter_test() {
+        use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+        let df = df!(
+            "ts" => ["2024-03-01 12:30"],
+            "day" => [Some("01.03.2024")],
+            "zoned" => ["2024-03-01 12:30 +0200"],
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            #[column(parse_datetime = "%Y-%m-%d %H:%M")]
+            ts: NaiveDateTime,
+            #[column(parse_datetime = "%d.%m.%Y")]
+            day: Option<NaiveDate>,
+            #[column("zoned", parse_datetime = "%Y-%m-%d %H:%M %z")]
+            local: DateTime<FixedOffset>,
+            #[column("zoned", parse_datetime = "%Y-%m-%d %H:%M %z")]
+            utc: DateTime<Utc>,
+        }
+
+        let row = df.rows_iter::<TestRow>().unwrap().next().unwrap().unwrap();
+
+        let ts = NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(12, 30, 0)
+            .unwrap();
+        let offset = FixedOffset::east_opt(7200).unwrap();
+
+        assert_eq!(row.ts, ts);
+        assert_eq!(row.day, NaiveDate::from_ymd_opt(2024, 3, 1));
+        assert_eq!(row.local, ts.and_local_timezone(offset).unwrap());
+        assert_eq!(row.utc, ts.and_local_timezone(offset).unwrap().to_utc());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn parse_datetime_errors_name_column_and_value() {
+        let df = df!("ts" => ["2024-03-01"]).unwrap();
+
+        let err_msg = df
+            .scalar_iter::<&str>("ts")
+            .unwrap()
+            .map(|value| parse_column_datetime::<chrono::NaiveDateTime>(value?, "%Y-%m-%d %H:%M", "ts"))
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            "Cannot parse value '2024-03-01' in column 'ts' with format '%Y-%m-%d %H:%M': premature end of input"
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use polars::prelude::*;
+
+/// Fieldless enums that are read from a single column value, implemented with `#[derive(FromColumnValue)]`.
+///
+/// `String`, `Categorical` and `Enum` columns are matched against the variant names, integer columns against
+/// the discriminants if the enum declares explicit ones.
+pub trait FromColumnValue: Sized {
+    const TYPE_NAME: &'static str;
+    const VARIANT_NAMES: &'static [&'static str];
+    const DISCRIMINANTS: Option<&'static [i128]>;
+
+    fn from_variant_index(index: usize) -> Self;
+}
+
+type ValueIter<'a, T> = Box<dyn Iterator<Item = Option<PolarsResult<T>>> + 'a>;
+
+#[doc(hidden)]
+pub fn create_column_value_iter<'a, T>(column: &'a Column) -> PolarsResult<ValueIter<'a, T>>
+where
+    T: FromColumnValue + 'a,
+{
+    let column_name = column.name().as_str();
+    let type_name = T::TYPE_NAME;
+
+    let iter: ValueIter<'a, T> = match column.dtype() {
+        DataType::String => Box::new(
+            column
+                .str()?
+                .iter()
+                .map(move |value| value.map(|value| find_variant_by_name::<T>(value, column_name))),
+        ),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, mapping) => {
+            let mapping = mapping.as_ref();
+            Box::new(create_category_id_iter(column)?.map(move |cat| {
+                cat.map(|cat| {
+                    let value = mapping.cat_to_str(cat).ok_or_else(
+                        || polars_err!(ComputeError: "Unknown category id {cat} in column '{column_name}'"),
+                    )?;
+                    find_variant_by_name::<T>(value, column_name)
+                })
+            }))
+        }
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Enum(categories, mapping) => {
+            let variant_indexes = map_enum_categories::<T>(categories, mapping, column_name)?;
+            Box::new(
+                create_category_id_iter(column)?
+                    .map(move |cat| cat.map(|cat| Ok(T::from_variant_index(variant_indexes[cat as usize])))),
+            )
+        }
+        DataType::Int8 => create_discriminant_iter::<T, Int8Type>(column)?,
+        DataType::Int16 => create_discriminant_iter::<T, Int16Type>(column)?,
+        DataType::Int32 => create_discriminant_iter::<T, Int32Type>(column)?,
+        DataType::Int64 => create_discriminant_iter::<T, Int64Type>(column)?,
+        DataType::UInt8 => create_discriminant_iter::<T, UInt8Type>(column)?,
+        DataType::UInt16 => create_discriminant_iter::<T, UInt16Type>(column)?,
+        DataType::UInt32 => create_discriminant_iter::<T, UInt32Type>(column)?,
+        DataType::UInt64 => create_discriminant_iter::<T, UInt64Type>(column)?,
+        dtype => {
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get {type_name} from column '{column_name}' with dtype : {dtype}. \
+                                             Make sure to enable 'dtype-categorical' feature for 'Categorical' and 'Enum' dtypes."),
+            )
+        }
+    };
+
+    Ok(iter)
+}
+
+fn find_variant_by_name<T: FromColumnValue>(value: &str, column_name: &str) -> PolarsResult<T> {
+    let type_name = T::TYPE_NAME;
+    T::VARIANT_NAMES
+        .iter()
+        .position(|name| *name == value)
+        .map(T::from_variant_index)
+        .ok_or_else(|| polars_err!(ComputeError: "Value '{value}' in column '{column_name}' does not match any variant of {type_name}"))
+}
+
+fn create_discriminant_iter<'a, T, P>(column: &'a Column) -> PolarsResult<ValueIter<'a, T>>
+where
+    T: FromColumnValue + 'a,
+    P: PolarsIntegerType,
+    P::Native: Into<i128>,
+{
+    let column_name = column.name().as_str();
+    let type_name = T::TYPE_NAME;
+
+    let Some(discriminants) = T::DISCRIMINANTS else {
+        return Err(
+            polars_err!(SchemaMismatch: "Cannot get {type_name} from integer column '{column_name}' because it has no explicit discriminants"),
+        );
+    };
+
+    let iter = column.as_materialized_series().unpack::<P>()?.iter().map(move |value| {
+        value.map(|value| {
+            let value: i128 = value.into();
+            discriminants
+                .iter()
+                .position(|discriminant| *discriminant == value)
+                .map(T::from_variant_index)
+                .ok_or_else(|| polars_err!(ComputeError: "Value {value} in column '{column_name}' does not match any discriminant of {type_name}"))
+        })
+    });
+
+    Ok(Box::new(iter))
+}
+
+#[cfg(feature = "dtype-categorical")]
+fn create_category_id_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<CatSize>> + 'a>> {
+    let iter: Box<dyn Iterator<Item = Option<CatSize>> + 'a> = match column.dtype().cat_physical()? {
+        CategoricalPhysical::U8 => Box::new(column.cat8()?.physical().iter().map(|cat| cat.map(CatSize::from))),
+        CategoricalPhysical::U16 => Box::new(column.cat16()?.physical().iter().map(|cat| cat.map(CatSize::from))),
+        CategoricalPhysical::U32 => Box::new(column.cat32()?.physical().iter()),
+    };
+
+    Ok(iter)
+}
+
+/// The categories of an `Enum` column are fixed, so every category is matched to its variant once up front.
+/// This reports categories without a variant even if they don't occur in the column.
+#[cfg(feature = "dtype-categorical")]
+fn map_enum_categories<T: FromColumnValue>(
+    categories: &FrozenCategories,
+    mapping: &CategoricalMapping,
+    column_name: &str,
+) -> PolarsResult<Vec<usize>> {
+    let type_name = T::TYPE_NAME;
+    let mut variant_indexes = vec![0; mapping.num_cats_upper_bound()];
+
+    for category in categories.categories().values_iter() {
+        let variant_index = T::VARIANT_NAMES.iter().position(|name| *name == category).ok_or_else(
+            || polars_err!(SchemaMismatch: "Category '{category}' of column '{column_name}' does not match any variant of {type_name}"),
+        )?;
+        let cat = mapping
+            .get_cat(category)
+            .ok_or_else(|| polars_err!(ComputeError: "Unknown category '{category}' in column '{column_name}'"))?;
+
+        variant_indexes[cat as usize] = variant_index;
+    }
+
+    Ok(variant_indexes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use itertools::Itertools;
+    use polars::prelude::*;
+
+    #[derive(Debug, PartialEq, FromColumnValue)]
+    #[from_column_value(convert_case(Snake))]
+    enum OrderStatus {
+        Pending,
+        InTransit,
+        #[from_column_value(rename("done"))]
+        Delivered,
+    }
+
+    #[derive(Debug, PartialEq, FromColumnValue)]
+    enum Priority {
+        Low = 1,
+        Medium = 5,
+        High = 10,
+    }
+
+    #[test]
+    fn enum_from_string_column() {
+        let df = df!(
+            "status" => &["pending", "in_transit", "done"],
+            "status_opt" => &[Some("done"), None, Some("pending")],
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            status: OrderStatus,
+            status_opt: Option<OrderStatus>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    status: OrderStatus::Pending,
+                    status_opt: Some(OrderStatus::Delivered)
+                },
+                TestRow {
+                    status: OrderStatus::InTransit,
+                    status_opt: None
+                },
+                TestRow {
+                    status: OrderStatus::Delivered,
+                    status_opt: Some(OrderStatus::Pending)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn enum_from_string_column_errors_on_unknown_value() {
+        let df = df!("status" => &["pending", "lost"]).unwrap();
+
+        let err_msg = df
+            .scalar_iter::<OrderStatus>("status")
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            "Value 'lost' in column 'status' does not match any variant of OrderStatus"
+        );
+    }
+
+    #[test]
+    fn enum_from_integer_column() {
+        let df = df!("priority" => &[Some(10u8), None, Some(1)]).unwrap();
+
+        let values = df
+            .scalar_iter::<Option<Priority>>("priority")
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![Some(Priority::High), None, Some(Priority::Low)]);
+
+        let df = df!("priority" => &[5i64, 2]).unwrap();
+
+        let err_msg = df
+            .scalar_iter::<Priority>("priority")
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            "Value 2 in column 'priority' does not match any discriminant of Priority"
+        );
+    }
+
+    #[test]
+    fn enum_from_integer_column_requires_explicit_discriminants() {
+        let df = df!("status" => &[0i32]).unwrap();
+
+        let err_msg = df.scalar_iter::<OrderStatus>("status").err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            "Cannot get OrderStatus from integer column 'status' because it has no explicit discriminants"
+        );
+    }
+
+    #[cfg(feature = "dtype-categorical")]
+    #[test]
+    fn enum_from_categorical_column() {
+        let cats = Categories::new(PlSmallStr::EMPTY, PlSmallStr::EMPTY, CategoricalPhysical::U32);
+        let dtype = DataType::from_categories(cats);
+
+        let column = Column::new("status".into(), &[Some("in_transit"), None, Some("pending")])
+            .cast(&dtype)
+            .unwrap();
+        let df = DataFrame::new(3, vec![column]).unwrap();
+
+        let values = df
+            .scalar_iter::<Option<OrderStatus>>("status")
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![Some(OrderStatus::InTransit), None, Some(OrderStatus::Pending)]
+        );
+    }
+
+    #[cfg(feature = "dtype-categorical")]
+    #[test]
+    fn enum_from_enum_column() {
+        let categories = FrozenCategories::new(["done", "pending", "in_transit"]).unwrap();
+        let dtype = DataType::from_frozen_categories(categories);
+
+        let column = Column::new("status".into(), &["pending", "done", "in_transit"])
+            .cast(&dtype)
+            .unwrap();
+        let df = DataFrame::new(3, vec![column]).unwrap();
+
+        let values = df
+            .scalar_iter::<OrderStatus>("status")
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![OrderStatus::Pending, OrderStatus::Delivered, OrderStatus::InTransit]
+        );
+    }
+
+    #[cfg(feature = "dtype-categorical")]
+    #[test]
+    fn enum_from_enum_column_checks_categories_up_front() {
+        let categories = FrozenCategories::new(["pending", "lost"]).unwrap();
+        let dtype = DataType::from_frozen_categories(categories);
+
+        let column = Column::new("status".into(), &["pending"]).cast(&dtype).unwrap();
+        let df = DataFrame::new(1, vec![column]).unwrap();
+
+        let err_msg = df.scalar_iter::<OrderStatus>("status").err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            "Category 'lost' of column 'status' does not match any variant of OrderStatus"
+        );
+    }
+}
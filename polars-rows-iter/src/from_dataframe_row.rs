@@ -17,3 +17,71 @@ pub trait FromDataFrameRow<'a> {
 
     fn create_builder() -> Self::Builder;
 }
+
+/// Like `FromDataFrameRow::from_dataframe`, but every column name is passed through `map_column_name` before
+/// the lookup. Implemented by the derive macro for fields with `#[column(flatten)]`, which read the columns of
+/// the flattened struct with the prefix and the name transformations of the outer struct. Not implemented for
+/// structs with `#[columns(...)]` fields, since their regex is matched against the column names as they are,
+/// or with `#[column(rest)]` fields, since they can't exclude the columns read by the outer struct.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used with `#[column(flatten)]`",
+    note = "flattened structs have to derive `FromDataFrameRow` and can't have `#[columns(...)]` or `#[column(rest)]` fields"
+)]
+pub trait FromDataFrameRowMapped<'a>: FromDataFrameRow<'a> {
+    fn from_dataframe_mapped(
+        dataframe: &'a DataFrame,
+        columns: HashMap<&'static str, String>,
+        map_column_name: &dyn Fn(&str) -> String,
+    ) -> PolarsResult<Box<dyn Iterator<Item = PolarsResult<Self>> + 'a>>
+    where
+        Self: Sized;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use polars::prelude::*;
+    use std::collections::HashMap;
+
+    struct Value(i64);
+
+    struct ValueBuilder;
+
+    impl ColumnNameBuilder for ValueBuilder {
+        fn build(self) -> HashMap<&'static str, String> {
+            HashMap::new()
+        }
+    }
+
+    // Implemented by hand, without the derive macro
+    impl<'a> FromDataFrameRow<'a> for Value {
+        type Builder = ValueBuilder;
+
+        fn from_dataframe(
+            dataframe: &'a DataFrame,
+            _columns: HashMap<&'static str, String>,
+        ) -> PolarsResult<Box<dyn Iterator<Item = PolarsResult<Self>> + 'a>> {
+            let iter = dataframe.scalar_iter::<i64>("value")?;
+            Ok(Box::new(iter.map(|value| value.map(Value))))
+        }
+
+        fn create_builder() -> Self::Builder {
+            ValueBuilder
+        }
+    }
+
+    #[test]
+    fn hand_written_impl_only_needs_from_dataframe() {
+        let df = df!("value" => [1i64, 2]).unwrap();
+
+        let values = df
+            .rows_iter::<Value>()
+            .unwrap()
+            .map(|row| row.map(|row| row.0))
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![1, 2]);
+    }
+}
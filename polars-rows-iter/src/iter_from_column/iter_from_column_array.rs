@@ -0,0 +1,198 @@
+#[cfg(feature = "dtype-array")]
+use crate::iter_from_column::iter_from_column_series::create_series_iter;
+use crate::{iter_from_column::iter_from_column_vec::collect_inner_values, *};
+use polars::prelude::*;
+
+impl<'a, T, const N: usize> IterFromColumn<'a> for [T; N]
+where
+    T: for<'inner> IterFromColumn<'inner>,
+{
+    type RawInner = Series;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Series>> + 'a> {
+        create_array_iter::<N>(column)
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<Series>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|series| collect_array_values::<T, N>(series, column_name))
+            .transpose()?
+            .ok_or_else(|| <Series as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
+    }
+}
+
+impl<'a, T, const N: usize> IterFromColumn<'a> for Option<[T; N]>
+where
+    T: for<'inner> IterFromColumn<'inner>,
+{
+    type RawInner = Series;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Series>> + 'a> {
+        create_array_iter::<N>(column)
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<Series>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|series| collect_array_values::<T, N>(series, column_name))
+            .transpose()
+    }
+}
+
+fn collect_array_values<T, const N: usize>(series: Series, column_name: &str) -> PolarsResult<[T; N]>
+where
+    T: for<'inner> IterFromColumn<'inner>,
+{
+    let values = collect_inner_values::<T>(series, column_name)?;
+    let len = values.len();
+
+    values
+        .try_into()
+        .map_err(|_| polars_err!(ShapeMismatch: "Cannot get array of size {N} from value with {len} elements in column '{column_name}'"))
+}
+
+#[cfg(feature = "dtype-array")]
+fn create_array_iter<'a, const N: usize>(
+    column: &'a Column,
+) -> PolarsResult<impl Iterator<Item = Option<Series>> + 'a> {
+    let column_name = column.name().as_str();
+    match column.dtype() {
+        DataType::Array(_, width) if *width == N => create_series_iter(column),
+        DataType::Array(_, width) => Err(
+            polars_err!(SchemaMismatch: "Cannot get array of size {N} from column '{column_name}' with width {width}"),
+        ),
+        dtype => {
+            Err(polars_err!(SchemaMismatch: "Cannot get [T; {N}] from column '{column_name}' with dtype: {dtype}"))
+        }
+    }
+}
+
+#[cfg(not(feature = "dtype-array"))]
+fn create_array_iter<'a, const N: usize>(
+    column: &'a Column,
+) -> PolarsResult<impl Iterator<Item = Option<Series>> + 'a> {
+    let column_name = column.name().as_str();
+    let dtype = column.dtype();
+    Err::<std::iter::Empty<_>, _>(
+        polars_err!(SchemaMismatch: "Cannot get [T; {N}] from column '{column_name}' with dtype '{dtype}'.\
+                                     Make sure to enable 'dtype-array' feature for 'Array' dtypes."),
+    )
+}
+
+#[cfg(all(test, feature = "dtype-array"))]
+mod tests {
+    use crate::*;
+    use itertools::Itertools;
+    use polars::prelude::*;
+
+    fn create_array_column(name: &str, values: Vec<Option<Series>>, width: usize) -> Column {
+        Column::new(name.into(), values)
+            .cast(&DataType::Array(Box::new(DataType::Float32), width))
+            .unwrap()
+    }
+
+    #[test]
+    fn array_f32_rows_iter_test() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1.0f32, 2.0, 3.0]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &[4.0f32, 5.0, 6.0]);
+
+        let col = create_array_column("col", vec![Some(s1.clone()), Some(s2.clone())], 3);
+        let col_opt = create_array_column("col_opt", vec![None, Some(s2)], 3);
+
+        let df = DataFrame::new(2, vec![col, col_opt]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: [f32; 3],
+            col_opt: Option<[f32; 3]>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    col: [1.0, 2.0, 3.0],
+                    col_opt: None
+                },
+                TestRow {
+                    col: [4.0, 5.0, 6.0],
+                    col_opt: Some([4.0, 5.0, 6.0])
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn vec_f32_from_array_scalar_iter_test() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &[Some(1.0f32), None]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &[Some(3.0f32), Some(4.0)]);
+
+        let col = create_array_column("col", vec![Some(s1), Some(s2)], 2);
+        let df = DataFrame::new(2, vec![col]).unwrap();
+
+        let values = df
+            .scalar_iter("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Vec<Option<f32>>>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![vec![Some(1.0), None], vec![Some(3.0), Some(4.0)]]);
+    }
+
+    #[test]
+    fn series_from_array_scalar_iter_test() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1.0f32, 2.0]);
+
+        let col = create_array_column("col", vec![Some(s1.clone()), None], 2);
+        let df = DataFrame::new(2, vec![col]).unwrap();
+
+        let values = df
+            .scalar_iter("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Option<Series>>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![Some(s1), None]);
+    }
+
+    #[test]
+    fn array_errors_on_width_mismatch() {
+        let column_name = "col";
+
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1.0f32, 2.0]);
+
+        let col = create_array_column(column_name, vec![Some(s1)], 2);
+        let df = DataFrame::new(1, vec![col]).unwrap();
+
+        let err_msg = df.scalar_iter::<[f32; 3]>(column_name).err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            format!("Cannot get array of size 3 from column '{column_name}' with width 2")
+        );
+    }
+
+    #[test]
+    fn array_errors_on_list_column() {
+        let column_name = "col";
+
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1.0f32, 2.0]);
+
+        let col = Column::new(column_name.into(), vec![s1]);
+        let df = DataFrame::new(1, vec![col]).unwrap();
+
+        let err_msg = df.scalar_iter::<[f32; 2]>(column_name).err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            format!("Cannot get [T; 2] from column '{column_name}' with dtype: list[f32]")
+        );
+    }
+}
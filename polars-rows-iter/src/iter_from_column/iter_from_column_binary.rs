@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{iter_from_column::iter_from_column_slice::create_slice_iter, *};
 use polars::prelude::*;
 
 impl<'a> IterFromColumn<'a> for &'a [u8] {
@@ -31,11 +31,14 @@ impl<'a> IterFromColumn<'a> for Option<&'a [u8]> {
     }
 }
 
-fn create_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<&'a [u8]>> + 'a> {
+pub(crate) fn create_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<&'a [u8]>> + 'a> {
     let column_name = column.name().as_str();
     let iter: Box<dyn Iterator<Item = Option<&[u8]>>> = match column.dtype() {
         DataType::Binary => Box::new(column.binary()?.iter()),
         DataType::BinaryOffset => Box::new(column.binary_offset()?.iter()),
+        DataType::List(inner_dtype) if **inner_dtype == DataType::UInt8 => {
+            Box::new(create_slice_iter::<UInt8Type>(column)?)
+        }
         dtype => {
             return Err(
                 polars_err!(SchemaMismatch: "Cannot get &[u8] from column '{column_name}' with dtype : {dtype}"),
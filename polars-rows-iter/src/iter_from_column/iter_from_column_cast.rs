@@ -0,0 +1,218 @@
+use super::*;
+use polars::prelude::*;
+
+/// Wrapper used for fields with `#[column(cast)]`, the column is cast to the dtype of `T` once before iterating.
+/// Values that don't fit into `T` are reported as row errors.
+#[doc(hidden)]
+pub struct Cast<T>(pub T);
+
+type CastIter<'a, T> = Box<dyn Iterator<Item = Option<PolarsResult<T>>> + 'a>;
+
+macro_rules! impl_iter_from_column_for_cast {
+    ($ty:ty, $polars_type:ty) => {
+        impl<'a> IterFromColumn<'a> for Cast<$ty> {
+            type RawInner = PolarsResult<$ty>;
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                create_cast_iter::<$polars_type>(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                column_name: &str,
+                _dtype: &DataType,
+            ) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                polars_value
+                    .ok_or_else(|| <Self as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?
+                    .map(Cast)
+            }
+        }
+
+        impl NullableIterFromColumn for Cast<$ty> {}
+    };
+}
+
+impl_iter_from_column_for_cast!(i8, Int8Type);
+impl_iter_from_column_for_cast!(i16, Int16Type);
+impl_iter_from_column_for_cast!(i32, Int32Type);
+impl_iter_from_column_for_cast!(i64, Int64Type);
+#[cfg(feature = "dtype-i128")]
+impl_iter_from_column_for_cast!(i128, Int128Type);
+impl_iter_from_column_for_cast!(u8, UInt8Type);
+impl_iter_from_column_for_cast!(u16, UInt16Type);
+impl_iter_from_column_for_cast!(u32, UInt32Type);
+impl_iter_from_column_for_cast!(u64, UInt64Type);
+#[cfg(feature = "dtype-u128")]
+impl_iter_from_column_for_cast!(u128, UInt128Type);
+impl_iter_from_column_for_cast!(f32, Float32Type);
+impl_iter_from_column_for_cast!(f64, Float64Type);
+
+fn create_cast_iter<'a, P>(column: &'a Column) -> PolarsResult<CastIter<'a, P::Native>>
+where
+    P: PolarsNumericType,
+{
+    let column_name = column.name().as_str();
+    let dtype = P::get_static_dtype();
+
+    if column.dtype() == &dtype {
+        let iter = column.as_materialized_series().unpack::<P>()?.iter();
+        return Ok(Box::new(iter.map(|value| value.map(Ok))));
+    }
+
+    if !column.dtype().is_primitive_numeric() {
+        let column_dtype = column.dtype();
+        return Err(
+            polars_err!(SchemaMismatch: "Cannot cast column '{column_name}' with dtype : {column_dtype} to {dtype}"),
+        );
+    }
+
+    // Polars casts values that don't fit into the target dtype to null, so they are found by comparing the validity
+    let cast_column = column.cast(&dtype)?;
+    let values = cast_column
+        .as_materialized_series()
+        .unpack::<P>()?
+        .iter()
+        .zip(column.is_not_null().iter())
+        .enumerate()
+        .map(|(row, (value, is_valid))| match (value, is_valid) {
+            (Some(value), _) => Some(Ok(value)),
+            (None, Some(true)) => {
+                let value = column.get(row).map(|value| value.to_string()).unwrap_or_default();
+                Some(Err(
+                    polars_err!(ComputeError: "Value {value} in column '{column_name}' is out of range for {dtype}"),
+                ))
+            }
+            (None, _) => None,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Box::new(values.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use polars::prelude::*;
+
+    #[test]
+    fn cast_narrows_columns() {
+        let df = df!(
+            "count" => [Some(1i64), None, Some(255)],
+            "ratio" => [0.5f64, 2.0, -1.0],
+            "rounded" => [1.9f64, 2.0, -1.2],
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            #[column(cast)]
+            count: Option<u8>,
+            #[column(cast)]
+            ratio: f32,
+            #[column(cast)]
+            rounded: i32,
+        }
+
+        let rows = df
+            .rows_iter::<TestRow>()
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    count: Some(1),
+                    ratio: 0.5,
+                    rounded: 1
+                },
+                TestRow {
+                    count: None,
+                    ratio: 2.0,
+                    rounded: 2
+                },
+                TestRow {
+                    count: Some(255),
+                    ratio: -1.0,
+                    rounded: -1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn cast_reports_out_of_range_values_as_row_errors() {
+        let df = df!("count" => [1i64, 256, -1]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow)]
+        struct TestRow {
+            #[column("count", cast)]
+            _count: u8,
+        }
+
+        let mut rows = df.rows_iter::<TestRow>().unwrap();
+
+        assert!(rows.next().unwrap().is_ok());
+        assert_eq!(
+            rows.next().unwrap().unwrap_err().to_string(),
+            "Value 256 in column 'count' is out of range for u8"
+        );
+        assert_eq!(
+            rows.next().unwrap().unwrap_err().to_string(),
+            "Value -1 in column 'count' is out of range for u8"
+        );
+    }
+
+    #[test]
+    fn lossy_cast_applies_to_numeric_fields() {
+        let df = df!(
+            "id" => [1i64, 2],
+            "score" => [0.25f64, 0.5],
+            "name" => ["a", "b"],
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        #[from_dataframe(cast = "lossy")]
+        struct TestRow {
+            id: u16,
+            score: Option<f32>,
+            name: String,
+        }
+
+        let rows = df
+            .rows_iter::<TestRow>()
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    id: 1,
+                    score: Some(0.25),
+                    name: "a".to_string()
+                },
+                TestRow {
+                    id: 2,
+                    score: Some(0.5),
+                    name: "b".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn cast_requires_numeric_columns() {
+        let df = df!("count" => ["1"]).unwrap();
+
+        let err_msg = df.scalar_iter::<Cast<u8>>("count").err().unwrap().to_string();
+
+        assert_eq!(err_msg, "Cannot cast column 'count' with dtype : str to u8");
+    }
+}
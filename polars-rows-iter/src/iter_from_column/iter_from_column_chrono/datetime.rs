@@ -1,4 +1,5 @@
-use crate::*;
+use super::create_utc_datetime;
+use crate::{iter_from_column::iter_from_column_temporal::create_instant_iter, *};
 use chrono::{DateTime, Utc};
 use polars::prelude::*;
 
@@ -41,24 +42,13 @@ impl<'a> IterFromColumn<'a> for Option<DateTime<Utc>> {
     }
 }
 
+/// Values of zoned columns are converted to UTC.
 fn create_datetime_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
-    Ok(column.datetime()?.phys.iter())
+    create_instant_iter(column, "chrono::DateTime<Utc>")
 }
 
 fn create_datetime(timestamp: i64, column_name: &str, dtype: &DataType) -> PolarsResult<DateTime<Utc>> {
-    let (time_unit, _time_zone) = if let DataType::Datetime(tu, tz) = dtype {
-        (tu, tz)
-    } else {
-        return Err(polars_err!(SchemaMismatch: "Unable to create chrono::DateTime from DataType: {dtype}"));
-    };
-
-    match time_unit {
-        TimeUnit::Nanoseconds => Ok(DateTime::from_timestamp_nanos(timestamp)),
-        TimeUnit::Microseconds => DateTime::from_timestamp_micros(timestamp)
-            .ok_or_else(|| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid microseconds timestamp")),
-        TimeUnit::Milliseconds => DateTime::from_timestamp_millis(timestamp)
-            .ok_or_else(|| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid nanoseconds timestamp")),
-    }
+    create_utc_datetime(timestamp, column_name, dtype)
 }
 
 #[cfg(test)]
@@ -138,4 +128,24 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn datetime_should_be_converted_to_utc_from_zoned_column() {
+        let time_zone = TimeZone::opt_try_new(Some("America/New_York")).unwrap();
+        let column = Int64Chunked::new("dt".into(), &vec![Some(1732122821000i64), None])
+            .into_datetime(TimeUnit::Milliseconds, time_zone)
+            .into_column();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let values = df
+            .scalar_iter("dt")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Option<DateTime<Utc>>>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![Some(DateTime::<Utc>::from_timestamp(1732122821, 0).unwrap()), None]
+        );
+    }
 }
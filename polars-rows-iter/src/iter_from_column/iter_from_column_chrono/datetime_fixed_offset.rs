@@ -0,0 +1,179 @@
+use super::create_utc_datetime;
+use crate::{
+    iter_from_column::iter_from_column_temporal::{create_zoned_datetime_iter, parse_fixed_offset},
+    *,
+};
+use chrono::{DateTime, FixedOffset, Offset, Utc};
+use polars::prelude::*;
+
+/// Offset of a zoned column, named time zones resolve to a different offset depending on the timestamp.
+#[derive(Clone, Copy)]
+pub enum ColumnOffset {
+    Fixed(FixedOffset),
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl<'a> IterFromColumn<'a> for DateTime<FixedOffset> {
+    type RawInner = (i64, ColumnOffset);
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<(i64, ColumnOffset)>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<(i64, ColumnOffset)>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let (timestamp, offset) = polars_value
+            .ok_or_else(|| <DateTime<FixedOffset> as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_datetime(timestamp, offset, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<DateTime<FixedOffset>> {
+    type RawInner = (i64, ColumnOffset);
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<(i64, ColumnOffset)>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<(i64, ColumnOffset)>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|(timestamp, offset)| create_datetime(timestamp, offset, column_name, dtype))
+            .transpose()
+    }
+}
+
+fn create_datetime_iter<'a>(
+    column: &'a Column,
+) -> PolarsResult<impl Iterator<Item = Option<(i64, ColumnOffset)>> + 'a> {
+    create_zoned_datetime_iter(
+        column,
+        "DateTime<FixedOffset>",
+        "NaiveDateTime or DateTime<Utc>",
+        resolve_offset,
+    )
+}
+
+fn resolve_offset(time_zone: &str, column_name: &str) -> PolarsResult<ColumnOffset> {
+    if time_zone == TimeZone::UTC.as_str() {
+        return Ok(ColumnOffset::Fixed(Utc.fix()));
+    }
+
+    if let Some(offset) = parse_fixed_offset(time_zone).and_then(FixedOffset::east_opt) {
+        return Ok(ColumnOffset::Fixed(offset));
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    if let Ok(time_zone) = time_zone.parse::<chrono_tz::Tz>() {
+        return Ok(ColumnOffset::Named(time_zone));
+    }
+
+    Err(
+        polars_err!(ComputeError: "Cannot resolve offset of time zone '{time_zone}' in column '{column_name}'. \
+                                   Make sure to enable 'chrono-tz' feature for named time zones."),
+    )
+}
+
+fn create_datetime(
+    timestamp: i64,
+    offset: ColumnOffset,
+    column_name: &str,
+    dtype: &DataType,
+) -> PolarsResult<DateTime<FixedOffset>> {
+    let datetime = create_utc_datetime(timestamp, column_name, dtype)?;
+
+    match offset {
+        ColumnOffset::Fixed(offset) => Ok(datetime.with_timezone(&offset)),
+        #[cfg(feature = "chrono-tz")]
+        ColumnOffset::Named(time_zone) => Ok(datetime.with_timezone(&time_zone).fixed_offset()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn create_zoned_column(name: &str, values: Vec<Option<i64>>, time_zone: &str) -> Column {
+        let time_zone = TimeZone::opt_try_new(Some(time_zone)).unwrap();
+        Int64Chunked::new(name.into(), &values)
+            .into_datetime(TimeUnit::Milliseconds, time_zone)
+            .into_column()
+    }
+
+    #[test]
+    fn datetime_fixed_offset_should_use_column_offset() {
+        let column = create_zoned_column("dt", vec![Some(1732122821000), None], "+02:00");
+        let column_utc = create_zoned_column("dt_utc", vec![Some(1732122821000), Some(1732122821000)], "UTC");
+
+        let df = DataFrame::new(2, vec![column, column_utc]).unwrap();
+
+        #[derive(Debug, PartialEq, FromDataFrameRow)]
+        struct TestRow {
+            dt: Option<DateTime<FixedOffset>>,
+            dt_utc: DateTime<FixedOffset>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|row| row.unwrap()).collect_vec();
+
+        let expected = DateTime::parse_from_rfc3339("2024-11-20T19:13:41+02:00").unwrap();
+        let expected_utc = DateTime::parse_from_rfc3339("2024-11-20T17:13:41+00:00").unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    dt: Some(expected),
+                    dt_utc: expected_utc
+                },
+                TestRow {
+                    dt: None,
+                    dt_utc: expected_utc
+                },
+            ]
+        );
+        assert_eq!(*rows[0].dt.unwrap().offset(), FixedOffset::east_opt(2 * 3600).unwrap());
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn datetime_fixed_offset_should_resolve_named_time_zone_per_value() {
+        let column = create_zoned_column("dt", vec![Some(1732122821000), Some(1720000000000)], "Europe/Berlin");
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let values = df
+            .scalar_iter("dt")
+            .unwrap()
+            .collect::<PolarsResult<Vec<DateTime<FixedOffset>>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![
+                DateTime::parse_from_rfc3339("2024-11-20T18:13:41+01:00").unwrap(),
+                DateTime::parse_from_rfc3339("2024-07-03T11:46:40+02:00").unwrap(),
+            ]
+        );
+        assert_eq!(values[1].offset().local_minus_utc(), 2 * 3600);
+    }
+
+    #[cfg(not(feature = "chrono-tz"))]
+    #[test]
+    fn datetime_fixed_offset_should_error_on_named_time_zone_without_feature() {
+        let column = create_zoned_column("dt", vec![Some(1732122821000)], "Europe/Berlin");
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        assert!(df.scalar_iter::<DateTime<FixedOffset>>("dt").is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use super::create_utc_datetime;
+use crate::{iter_from_column::iter_from_column_temporal::create_zoned_datetime_iter, *};
+use chrono::DateTime;
+use chrono_tz::Tz;
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for DateTime<Tz> {
+    type RawInner = (i64, Tz);
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<(i64, Tz)>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<(i64, Tz)>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let (timestamp, time_zone) = polars_value
+            .ok_or_else(|| <DateTime<Tz> as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_datetime(timestamp, time_zone, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<DateTime<Tz>> {
+    type RawInner = (i64, Tz);
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<(i64, Tz)>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<(i64, Tz)>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|(timestamp, time_zone)| create_datetime(timestamp, time_zone, column_name, dtype))
+            .transpose()
+    }
+}
+
+fn create_datetime_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<(i64, Tz)>> + 'a> {
+    create_zoned_datetime_iter(
+        column,
+        "DateTime<chrono_tz::Tz>",
+        "NaiveDateTime or DateTime<Utc>",
+        |time_zone, column_name| {
+            time_zone.parse::<Tz>().map_err(
+            |_| polars_err!(ComputeError: "Unknown time zone '{time_zone}' in column '{column_name}'. Use DateTime<FixedOffset> for fixed offsets."),
+        )
+        },
+    )
+}
+
+fn create_datetime(timestamp: i64, time_zone: Tz, column_name: &str, dtype: &DataType) -> PolarsResult<DateTime<Tz>> {
+    create_utc_datetime(timestamp, column_name, dtype).map(|dt| dt.with_timezone(&time_zone))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use chrono_tz::{Asia::Tokyo, Europe::Berlin};
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn create_zoned_column(name: &str, values: Vec<Option<i64>>, time_zone: &str) -> Column {
+        let time_zone = TimeZone::opt_try_new(Some(time_zone)).unwrap();
+        Int64Chunked::new(name.into(), &values)
+            .into_datetime(TimeUnit::Milliseconds, time_zone)
+            .into_column()
+    }
+
+    #[test]
+    fn datetime_tz_should_use_column_time_zone() {
+        let column_berlin = create_zoned_column(
+            "berlin",
+            vec![Some(1732122821000), Some(1720000000000)],
+            "Europe/Berlin",
+        );
+        let column_tokyo = create_zoned_column("tokyo", vec![None, Some(1732122821000)], "Asia/Tokyo");
+
+        let df = DataFrame::new(2, vec![column_berlin, column_tokyo]).unwrap();
+
+        #[derive(Debug, PartialEq, FromDataFrameRow)]
+        struct TestRow {
+            berlin: DateTime<Tz>,
+            tokyo: Option<DateTime<Tz>>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|row| row.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    berlin: Berlin.with_ymd_and_hms(2024, 11, 20, 18, 13, 41).unwrap(),
+                    tokyo: None,
+                },
+                TestRow {
+                    berlin: Berlin.with_ymd_and_hms(2024, 7, 3, 11, 46, 40).unwrap(),
+                    tokyo: Some(Tokyo.with_ymd_and_hms(2024, 11, 21, 2, 13, 41).unwrap()),
+                },
+            ]
+        );
+        assert_eq!(rows[0].berlin.timezone(), Berlin);
+        assert_eq!(rows[1].tokyo.unwrap().timezone(), Tokyo);
+    }
+
+    #[test]
+    fn datetime_tz_should_error_on_column_without_time_zone() {
+        let column = Column::new("dt".into(), vec![1732122821000i64])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let err_msg = df.scalar_iter::<DateTime<Tz>>("dt").err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            "Cannot get DateTime<chrono_tz::Tz> from column 'dt' without time zone. Use NaiveDateTime or DateTime<Utc> instead."
+        );
+    }
+}
@@ -1,2 +1,19 @@
 mod datetime;
+mod datetime_fixed_offset;
+#[cfg(feature = "chrono-tz")]
+mod datetime_tz;
+mod naivedate;
 mod naivedatetime;
+mod naivetime;
+mod timedelta;
+
+use crate::iter_from_column::iter_from_column_temporal::{get_datetime_time_unit, split_seconds};
+use chrono::{DateTime, Utc};
+use polars::prelude::*;
+
+fn create_utc_datetime(timestamp: i64, column_name: &str, dtype: &DataType) -> PolarsResult<DateTime<Utc>> {
+    let (seconds, nanoseconds) = split_seconds(timestamp, get_datetime_time_unit(dtype, "chrono::DateTime")?);
+
+    DateTime::from_timestamp(seconds, nanoseconds)
+        .ok_or_else(|| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid timestamp"))
+}
@@ -1,5 +1,6 @@
-use crate::*;
-use chrono::{DateTime, NaiveDateTime};
+use super::create_utc_datetime;
+use crate::{iter_from_column::iter_from_column_temporal::create_naive_datetime_iter, *};
+use chrono::NaiveDateTime;
 use polars::prelude::*;
 
 impl<'a> IterFromColumn<'a> for NaiveDateTime {
@@ -42,29 +43,21 @@ impl<'a> IterFromColumn<'a> for Option<NaiveDateTime> {
 }
 
 fn create_datetime_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
-    Ok(column.datetime()?.phys.iter())
+    create_naive_datetime_iter(
+        column,
+        "chrono::NaiveDateTime",
+        "DateTime<Utc>, DateTime<FixedOffset> or DateTime<chrono_tz::Tz>",
+    )
 }
 
 fn create_datetime(timestamp: i64, column_name: &str, dtype: &DataType) -> PolarsResult<NaiveDateTime> {
-    let (time_unit, _time_zone) = if let DataType::Datetime(tu, tz) = dtype {
-        (tu, tz)
-    } else {
-        return Err(polars_err!(SchemaMismatch: "Unable to create chrono::DateTime from DataType: {dtype}"));
-    };
-
-    match time_unit {
-        TimeUnit::Nanoseconds => Ok(DateTime::from_timestamp_nanos(timestamp).naive_utc()),
-        TimeUnit::Microseconds => DateTime::from_timestamp_micros(timestamp).map(|dt|dt.naive_utc())
-            .ok_or_else(|| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid microseconds timestamp")),
-        TimeUnit::Milliseconds => DateTime::from_timestamp_millis(timestamp).map(|dt|dt.naive_utc())
-            .ok_or_else(|| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid nanoseconds timestamp")),
-    }
+    create_utc_datetime(timestamp, column_name, dtype).map(|dt| dt.naive_utc())
 }
 
 #[cfg(test)]
 mod tests {
 
-    use chrono::Utc;
+    use chrono::{DateTime, Utc};
     use itertools::Itertools;
 
     use super::*;
@@ -139,4 +132,21 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn naive_datetime_should_error_on_zoned_column() {
+        let time_zone = TimeZone::opt_try_new(Some("Europe/Berlin")).unwrap();
+        let column = Int64Chunked::new("dt".into(), &vec![1732122821000i64])
+            .into_datetime(TimeUnit::Milliseconds, time_zone)
+            .into_column();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let err_msg = df.scalar_iter::<NaiveDateTime>("dt").err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            "Cannot get chrono::NaiveDateTime from column 'dt' with time zone 'Europe/Berlin'. \
+             Use DateTime<Utc>, DateTime<FixedOffset> or DateTime<chrono_tz::Tz> instead."
+        );
+    }
 }
@@ -0,0 +1,121 @@
+use crate::{
+    iter_from_column::iter_from_column_temporal::{create_time_iter, split_time_of_day},
+    *,
+};
+use chrono::NaiveTime;
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for NaiveTime {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_time_iter(column, "chrono::NaiveTime")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let nanoseconds =
+            polars_value.ok_or_else(|| <NaiveTime as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_time(nanoseconds, column_name)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<NaiveTime> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_time_iter(column, "chrono::NaiveTime")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|nanoseconds| create_time(nanoseconds, column_name))
+            .transpose()
+    }
+}
+
+/// Time columns store nanoseconds since midnight.
+fn create_time(nanoseconds: i64, column_name: &str) -> PolarsResult<NaiveTime> {
+    split_time_of_day(nanoseconds)
+        .and_then(|(hour, minute, second, nanosecond)| NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond))
+        .ok_or_else(|| polars_err!(OutOfBounds: "Value {nanoseconds} in column {column_name} is not a valid time"))
+}
+
+#[cfg(all(test, feature = "dtype-time"))]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+
+    #[test]
+    fn naive_time_test() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Time;
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let col_values = col
+            .as_materialized_series()
+            .time()
+            .unwrap()
+            .as_time_iter()
+            .map(|v| v.unwrap())
+            .collect_vec();
+        let col_opt_values = col_opt
+            .as_materialized_series()
+            .time()
+            .unwrap()
+            .as_time_iter()
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: NaiveTime,
+            col_opt: Option<NaiveTime>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+
+    #[test]
+    fn naive_time_should_keep_nanoseconds() {
+        let column = Column::new("time".into(), vec![0i64, 45_296_000_000_123])
+            .cast(&DataType::Time)
+            .unwrap();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let values = df
+            .scalar_iter("time")
+            .unwrap()
+            .collect::<PolarsResult<Vec<NaiveTime>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![NaiveTime::MIN, NaiveTime::from_hms_nano_opt(12, 34, 56, 123).unwrap(),]
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    iter_from_column::iter_from_column_temporal::{create_duration_iter, get_duration_time_unit, split_seconds},
+    *,
+};
+use chrono::TimeDelta;
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for TimeDelta {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_duration_iter(column, "chrono::TimeDelta")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let value =
+            polars_value.ok_or_else(|| <TimeDelta as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_time_delta(value, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<TimeDelta> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_duration_iter(column, "chrono::TimeDelta")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|value| create_time_delta(value, column_name, dtype))
+            .transpose()
+    }
+}
+
+fn create_time_delta(value: i64, column_name: &str, dtype: &DataType) -> PolarsResult<TimeDelta> {
+    let (seconds, nanoseconds) = split_seconds(value, get_duration_time_unit(dtype, "chrono::TimeDelta")?);
+
+    TimeDelta::new(seconds, nanoseconds).ok_or_else(
+        || polars_err!(OutOfBounds: "Value {value} in column {column_name} is not a valid chrono::TimeDelta"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+
+    fn time_delta_test(time_unit: TimeUnit, to_time_delta: fn(i64) -> TimeDelta) {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Duration(time_unit);
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let col_values = col
+            .duration()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| to_time_delta(v.unwrap()))
+            .collect_vec();
+        let col_opt_values = col_opt
+            .duration()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| v.map(to_time_delta))
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: TimeDelta,
+            col_opt: Option<TimeDelta>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+
+    #[test]
+    fn time_delta_millis_test() {
+        time_delta_test(TimeUnit::Milliseconds, |v| TimeDelta::try_milliseconds(v).unwrap());
+    }
+
+    #[test]
+    fn time_delta_micros_test() {
+        time_delta_test(TimeUnit::Microseconds, TimeDelta::microseconds);
+    }
+
+    #[test]
+    fn time_delta_nanos_test() {
+        time_delta_test(TimeUnit::Nanoseconds, TimeDelta::nanoseconds);
+    }
+}
@@ -0,0 +1,140 @@
+use super::*;
+use polars::prelude::*;
+use std::borrow::Cow;
+
+/// Borrowed from string columns. Binary columns are read as UTF-8, values with invalid UTF-8 are converted
+/// lossily into owned strings.
+impl<'a> IterFromColumn<'a> for Cow<'a, str> {
+    type RawInner = Cow<'a, str>;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+        let iter: Box<dyn Iterator<Item = Option<Cow<'a, str>>> + 'a> = match column.dtype() {
+            DataType::Binary | DataType::BinaryOffset => {
+                Box::new(iter_from_column_binary::create_iter(column)?.map(|value| value.map(String::from_utf8_lossy)))
+            }
+            _ => Box::new(iter_from_column_str::create_iter(column)?.map(|value| value.map(Cow::Borrowed))),
+        };
+
+        Ok(iter)
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<Self::RawInner>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value.ok_or_else(|| <Self as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
+    }
+}
+
+impl NullableIterFromColumn for Cow<'_, str> {}
+
+/// Borrowed from binary columns and lists of `UInt8` without inner nulls.
+impl<'a> IterFromColumn<'a> for Cow<'a, [u8]> {
+    type RawInner = &'a [u8];
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+        iter_from_column_binary::create_iter(column)
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<Self::RawInner>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(Cow::Borrowed)
+            .ok_or_else(|| <Self as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
+    }
+}
+
+impl NullableIterFromColumn for Cow<'_, [u8]> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use polars::prelude::*;
+    use std::borrow::Cow;
+
+    fn fold_case(value: &str) -> PolarsResult<Cow<'_, str>> {
+        Ok(match value.chars().any(char::is_uppercase) {
+            true => Cow::Owned(value.to_lowercase()),
+            false => Cow::Borrowed(value),
+        })
+    }
+
+    #[test]
+    fn cow_str_rows_iter_test() {
+        let df = df!(
+            "name" => ["a", "b"],
+            "name_opt" => [Some("c"), None],
+            "raw" => [b"ok".as_slice(), b"\xffx".as_slice()],
+            "label" => ["Upper", "lower"],
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow<'a> {
+            name: Cow<'a, str>,
+            name_opt: Option<Cow<'a, str>>,
+            raw: Cow<'a, str>,
+            #[column(with = fold_case, from = &str)]
+            label: Cow<'a, str>,
+        }
+
+        let rows = df
+            .rows_iter::<TestRow>()
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    name: Cow::Borrowed("a"),
+                    name_opt: Some(Cow::Borrowed("c")),
+                    raw: Cow::Borrowed("ok"),
+                    label: Cow::Owned("upper".to_string()),
+                },
+                TestRow {
+                    name: Cow::Borrowed("b"),
+                    name_opt: None,
+                    raw: Cow::Owned("\u{fffd}x".to_string()),
+                    label: Cow::Borrowed("lower"),
+                },
+            ]
+        );
+
+        assert!(matches!(rows[0].name, Cow::Borrowed(_)));
+        assert!(matches!(rows[0].raw, Cow::Borrowed(_)));
+        assert!(matches!(rows[0].label, Cow::Owned(_)));
+        assert!(matches!(rows[1].raw, Cow::Owned(_)));
+        assert!(matches!(rows[1].label, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn cow_bytes_scalar_iter_test() {
+        let df = df!("col" => [Some(b"ab".as_slice()), None]).unwrap();
+
+        let values = df
+            .scalar_iter::<Option<Cow<[u8]>>>("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![Some(Cow::Borrowed(b"ab".as_slice())), None]);
+        assert!(matches!(values[0], Some(Cow::Borrowed(_))));
+
+        let err_msg = df
+            .scalar_iter::<Cow<[u8]>>("col")
+            .unwrap()
+            .nth(1)
+            .unwrap()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            "Found unexpected None/null value in column 'col' with mandatory values!"
+        );
+    }
+}
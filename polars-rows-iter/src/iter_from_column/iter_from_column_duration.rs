@@ -0,0 +1,132 @@
+use crate::{
+    iter_from_column::iter_from_column_temporal::{create_duration_iter, get_duration_time_unit, split_seconds},
+    *,
+};
+use polars::prelude::*;
+use std::time::Duration;
+
+impl<'a> IterFromColumn<'a> for Duration {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+        create_duration_iter(column, "std::time::Duration")
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let value =
+            polars_value.ok_or_else(|| <Duration as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_duration(value, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<Duration> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+        create_duration_iter(column, "std::time::Duration")
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|value| create_duration(value, column_name, dtype))
+            .transpose()
+    }
+}
+
+fn create_duration(value: i64, column_name: &str, dtype: &DataType) -> PolarsResult<Duration> {
+    let (seconds, nanoseconds) = split_seconds(value, get_duration_time_unit(dtype, "std::time::Duration")?);
+
+    u64::try_from(seconds)
+        .map(|seconds| Duration::new(seconds, nanoseconds))
+        .map_err(|_| polars_err!(OutOfBounds: "Negative value {value} in column {column_name} cannot be represented as std::time::Duration"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use itertools::Itertools;
+    use polars::prelude::*;
+    use std::time::Duration;
+
+    fn create_duration_column(name: &str, values: Vec<Option<i64>>, time_unit: TimeUnit) -> Column {
+        Column::new(name.into(), values)
+            .cast(&DataType::Duration(time_unit))
+            .unwrap()
+    }
+
+    #[test]
+    fn duration_should_respect_time_units() {
+        let ms = create_duration_column("ms", vec![Some(1_500), Some(0)], TimeUnit::Milliseconds);
+        let us = create_duration_column("us", vec![Some(1_500), None], TimeUnit::Microseconds);
+        let ns = create_duration_column("ns", vec![Some(1_500), Some(i64::MAX)], TimeUnit::Nanoseconds);
+
+        let df = DataFrame::new(2, vec![ms, us, ns]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            ms: Duration,
+            us: Option<Duration>,
+            ns: Duration,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    ms: Duration::from_millis(1_500),
+                    us: Some(Duration::from_micros(1_500)),
+                    ns: Duration::from_nanos(1_500),
+                },
+                TestRow {
+                    ms: Duration::ZERO,
+                    us: None,
+                    ns: Duration::from_nanos(i64::MAX as u64),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn duration_should_error_on_negative_values() {
+        let column_name = "latency";
+
+        let col = create_duration_column(column_name, vec![Some(10), Some(-1)], TimeUnit::Milliseconds);
+        let df = DataFrame::new(2, vec![col]).unwrap();
+
+        let err_msg = df
+            .scalar_iter(column_name)
+            .unwrap()
+            .collect::<PolarsResult<Vec<Duration>>>()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            format!("Negative value -1 in column {column_name} cannot be represented as std::time::Duration")
+        );
+    }
+
+    #[test]
+    fn duration_should_error_on_non_duration_column() {
+        let column_name = "latency";
+
+        let col = Column::new(column_name.into(), vec![10i64]);
+        let df = DataFrame::new(1, vec![col]).unwrap();
+
+        let err_msg = df.scalar_iter::<Duration>(column_name).err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            format!("Cannot get std::time::Duration from column '{column_name}' with dtype : i64")
+        );
+    }
+}
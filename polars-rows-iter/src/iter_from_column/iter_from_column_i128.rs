@@ -0,0 +1,102 @@
+use super::*;
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for i128 {
+    type RawInner = i128;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i128>> + 'a> {
+        create_primitive_iter(column, create_iter)
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<i128>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value.ok_or_else(|| <i128 as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<i128> {
+    type RawInner = i128;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i128>> + 'a> {
+        create_primitive_iter(column, create_iter)
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<i128>, _column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(polars_value)
+    }
+}
+
+pub(crate) fn create_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i128>> + 'a> {
+    let column_name = column.name().as_str();
+    let iter = match column.dtype() {
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => column.i128()?.iter(),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => column.decimal()?.phys.iter(),
+        dtype => {
+            return Err(polars_err!(SchemaMismatch: "Cannot get i128 from column '{column_name}' with dtype : {dtype}"))
+        }
+    };
+
+    Ok(iter)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[cfg(feature = "dtype-i128")]
+    use itertools::izip;
+    use itertools::Itertools;
+    use polars::prelude::*;
+    #[cfg(feature = "dtype-i128")]
+    use rand::{rngs::StdRng, SeedableRng};
+    #[cfg(feature = "dtype-i128")]
+    use testing::*;
+
+    #[cfg(feature = "dtype-i128")]
+    const ROW_COUNT: usize = 64;
+
+    #[cfg(feature = "dtype-i128")]
+    create_rows_iter_test_for_chunked_type!(i128_test, i128, i128, DataType::Int128, ROW_COUNT);
+
+    #[cfg(feature = "dtype-decimal")]
+    #[test]
+    fn i128_as_decimal_mantissa_test() {
+        let col = Int128Chunked::from_slice("col".into(), &[12345, -1, 0])
+            .into_decimal_unchecked(10, 2)
+            .into_column();
+        let col_opt = Int128Chunked::from_slice_options("col_opt".into(), &[Some(7), None, Some(-700)])
+            .into_decimal_unchecked(10, 2)
+            .into_column();
+
+        let df = DataFrame::new(3, vec![col, col_opt]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: i128,
+            col_opt: Option<i128>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    col: 12345,
+                    col_opt: Some(7)
+                },
+                TestRow { col: -1, col_opt: None },
+                TestRow {
+                    col: 0,
+                    col_opt: Some(-700)
+                },
+            ]
+        );
+    }
+}
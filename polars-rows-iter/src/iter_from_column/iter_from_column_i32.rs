@@ -4,7 +4,7 @@ use polars::prelude::*;
 impl<'a> IterFromColumn<'a> for i32 {
     type RawInner = i32;
     fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i32>> + 'a> {
-        create_iter(column)
+        create_primitive_iter(column, create_iter)
     }
 
     #[inline]
@@ -19,7 +19,7 @@ impl<'a> IterFromColumn<'a> for i32 {
 impl<'a> IterFromColumn<'a> for Option<i32> {
     type RawInner = i32;
     fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i32>> + 'a> {
-        create_iter(column)
+        create_primitive_iter(column, create_iter)
     }
 
     #[inline]
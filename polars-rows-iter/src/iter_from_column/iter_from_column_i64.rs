@@ -4,7 +4,7 @@ use polars::prelude::*;
 impl<'a> IterFromColumn<'a> for i64 {
     type RawInner = i64;
     fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
-        create_iter(column)
+        create_primitive_iter(column, create_iter)
     }
 
     #[inline]
@@ -19,7 +19,7 @@ impl<'a> IterFromColumn<'a> for i64 {
 impl<'a> IterFromColumn<'a> for Option<i64> {
     type RawInner = i64;
     fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
-        create_iter(column)
+        create_primitive_iter(column, create_iter)
     }
 
     #[inline]
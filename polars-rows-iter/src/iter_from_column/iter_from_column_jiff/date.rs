@@ -0,0 +1,102 @@
+use crate::{
+    iter_from_column::iter_from_column_temporal::{create_date_iter, SECONDS_PER_DAY},
+    *,
+};
+use jiff::{civil::Date, tz, Timestamp};
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for Date {
+    type RawInner = i32;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i32>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_date_iter(column, "jiff::civil::Date")
+    }
+
+    fn get_value(polars_value: Option<i32>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let days =
+            polars_value.ok_or_else(|| <Date as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_date(days, column_name)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<Date> {
+    type RawInner = i32;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i32>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_date_iter(column, "jiff::civil::Date")
+    }
+
+    fn get_value(polars_value: Option<i32>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value.map(|days| create_date(days, column_name)).transpose()
+    }
+}
+
+fn create_date(days: i32, column_name: &str) -> PolarsResult<Date> {
+    Timestamp::from_second(days as i64 * SECONDS_PER_DAY)
+        .map(|timestamp| tz::TimeZone::UTC.to_datetime(timestamp).date())
+        .map_err(|_| polars_err!(OutOfBounds: "Value {days} in column {column_name} is not a valid date"))
+}
+
+#[cfg(test)]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use jiff::{civil::date, ToSpan};
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+
+    #[test]
+    fn date_test() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Date;
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let to_date = |days: i32| date(1970, 1, 1).checked_add(days.days()).unwrap();
+        let col_values = col
+            .date()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| to_date(v.unwrap()))
+            .collect_vec();
+        let col_opt_values = col_opt
+            .date()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| v.map(to_date))
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: Date,
+            col_opt: Option<Date>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+}
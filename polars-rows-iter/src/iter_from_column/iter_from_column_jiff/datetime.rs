@@ -0,0 +1,90 @@
+use super::create_timestamp;
+use crate::{iter_from_column::iter_from_column_temporal::create_naive_datetime_iter, *};
+use jiff::{civil::DateTime, tz};
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for DateTime {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let timestamp =
+            polars_value.ok_or_else(|| <DateTime as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_datetime(timestamp, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<DateTime> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|timestamp| create_datetime(timestamp, column_name, dtype))
+            .transpose()
+    }
+}
+
+fn create_datetime_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+    create_naive_datetime_iter(column, "jiff::civil::DateTime", "jiff::Timestamp or jiff::Zoned")
+}
+
+fn create_datetime(timestamp: i64, column_name: &str, dtype: &DataType) -> PolarsResult<DateTime> {
+    create_timestamp(timestamp, column_name, dtype).map(|timestamp| tz::TimeZone::UTC.to_datetime(timestamp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jiff::civil::date;
+
+    #[test]
+    fn datetime_should_be_read_from_datetime_nanos() {
+        let column = Column::new("dt".into(), vec![Some(1732122821123456789i64), None])
+            .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+            .unwrap();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let values = df
+            .scalar_iter("dt")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Option<DateTime>>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![Some(date(2024, 11, 20).at(17, 13, 41, 123456789)), None]);
+    }
+
+    #[test]
+    fn datetime_should_error_on_zoned_column() {
+        let time_zone = TimeZone::opt_try_new(Some("Europe/Berlin")).unwrap();
+        let column = Int64Chunked::new("dt".into(), &[1732122821000i64])
+            .into_datetime(TimeUnit::Milliseconds, time_zone)
+            .into_column();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let err_msg = df.scalar_iter::<DateTime>("dt").err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            "Cannot get jiff::civil::DateTime from column 'dt' with time zone 'Europe/Berlin'. \
+             Use jiff::Timestamp or jiff::Zoned instead."
+        );
+    }
+}
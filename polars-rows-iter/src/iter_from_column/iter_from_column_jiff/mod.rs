@@ -0,0 +1,17 @@
+mod date;
+mod datetime;
+mod signed_duration;
+mod time;
+mod timestamp;
+mod zoned;
+
+use crate::iter_from_column::iter_from_column_temporal::{get_datetime_time_unit, split_seconds};
+use jiff::Timestamp;
+use polars::prelude::*;
+
+fn create_timestamp(timestamp: i64, column_name: &str, dtype: &DataType) -> PolarsResult<Timestamp> {
+    let (seconds, nanoseconds) = split_seconds(timestamp, get_datetime_time_unit(dtype, "jiff::Timestamp")?);
+
+    Timestamp::new(seconds, nanoseconds as i32)
+        .map_err(|_| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid timestamp"))
+}
@@ -0,0 +1,113 @@
+use crate::{
+    iter_from_column::iter_from_column_temporal::{create_duration_iter, get_duration_time_unit, split_seconds},
+    *,
+};
+use jiff::SignedDuration;
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for SignedDuration {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_duration_iter(column, "jiff::SignedDuration")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let value = polars_value
+            .ok_or_else(|| <SignedDuration as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_duration(value, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<SignedDuration> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_duration_iter(column, "jiff::SignedDuration")
+    }
+
+    fn get_value(polars_value: Option<i64>, _column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value.map(|value| create_duration(value, dtype)).transpose()
+    }
+}
+
+fn create_duration(value: i64, dtype: &DataType) -> PolarsResult<SignedDuration> {
+    let (seconds, nanoseconds) = split_seconds(value, get_duration_time_unit(dtype, "jiff::SignedDuration")?);
+    Ok(SignedDuration::new(seconds, nanoseconds as i32))
+}
+
+#[cfg(test)]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+
+    fn signed_duration_test(time_unit: TimeUnit, to_duration: fn(i64) -> SignedDuration) {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Duration(time_unit);
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let col_values = col
+            .duration()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| to_duration(v.unwrap()))
+            .collect_vec();
+        let col_opt_values = col_opt
+            .duration()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| v.map(to_duration))
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: SignedDuration,
+            col_opt: Option<SignedDuration>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+
+    #[test]
+    fn signed_duration_millis_test() {
+        signed_duration_test(TimeUnit::Milliseconds, SignedDuration::from_millis);
+    }
+
+    #[test]
+    fn signed_duration_micros_test() {
+        signed_duration_test(TimeUnit::Microseconds, SignedDuration::from_micros);
+    }
+
+    #[test]
+    fn signed_duration_nanos_test() {
+        signed_duration_test(TimeUnit::Nanoseconds, SignedDuration::from_nanos);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    iter_from_column::iter_from_column_temporal::{create_time_iter, split_time_of_day},
+    *,
+};
+use jiff::civil::Time;
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for Time {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_time_iter(column, "jiff::civil::Time")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let nanoseconds =
+            polars_value.ok_or_else(|| <Time as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_time(nanoseconds, column_name)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<Time> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_time_iter(column, "jiff::civil::Time")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|nanoseconds| create_time(nanoseconds, column_name))
+            .transpose()
+    }
+}
+
+/// Time columns store nanoseconds since midnight.
+fn create_time(nanoseconds: i64, column_name: &str) -> PolarsResult<Time> {
+    split_time_of_day(nanoseconds)
+        .and_then(|(hour, minute, second, nanosecond)| {
+            Time::new(hour as i8, minute as i8, second as i8, nanosecond as i32).ok()
+        })
+        .ok_or_else(|| polars_err!(OutOfBounds: "Value {nanoseconds} in column {column_name} is not a valid time"))
+}
+
+#[cfg(all(test, feature = "dtype-time"))]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use jiff::SignedDuration;
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+
+    #[test]
+    fn time_test() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Time;
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let to_time = |ns: i64| Time::midnight().checked_add(SignedDuration::from_nanos(ns)).unwrap();
+        let col_values = col
+            .as_materialized_series()
+            .time()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| to_time(v.unwrap()))
+            .collect_vec();
+        let col_opt_values = col_opt
+            .as_materialized_series()
+            .time()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| v.map(to_time))
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: Time,
+            col_opt: Option<Time>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+}
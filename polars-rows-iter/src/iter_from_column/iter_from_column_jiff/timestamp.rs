@@ -0,0 +1,123 @@
+use super::create_timestamp;
+use crate::{iter_from_column::iter_from_column_temporal::create_instant_iter, *};
+use jiff::Timestamp;
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for Timestamp {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_instant_iter(column, "jiff::Timestamp")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let timestamp =
+            polars_value.ok_or_else(|| <Timestamp as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_timestamp(timestamp, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<Timestamp> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_instant_iter(column, "jiff::Timestamp")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|timestamp| create_timestamp(timestamp, column_name, dtype))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+
+    #[test]
+    fn timestamp_test() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Datetime(TimeUnit::Nanoseconds, None);
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let to_timestamp = |ns: i64| Timestamp::from_nanosecond(ns as i128).unwrap();
+        let col_values = col
+            .datetime()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| to_timestamp(v.unwrap()))
+            .collect_vec();
+        let col_opt_values = col_opt
+            .datetime()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| v.map(to_timestamp))
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: Timestamp,
+            col_opt: Option<Timestamp>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+
+    #[test]
+    fn timestamp_should_respect_time_units() {
+        let ms = Column::new("ms".into(), vec![-1i64])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let us = Column::new("us".into(), vec![1732122821123456i64])
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap();
+
+        let df = DataFrame::new(1, vec![ms, us]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            ms: Timestamp,
+            us: Timestamp,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![TestRow {
+                ms: "1969-12-31T23:59:59.999Z".parse().unwrap(),
+                us: "2024-11-20T17:13:41.123456Z".parse().unwrap(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use super::create_timestamp;
+use crate::{
+    iter_from_column::iter_from_column_temporal::{create_zoned_datetime_iter, parse_fixed_offset},
+    *,
+};
+use jiff::{
+    tz::{self, Offset},
+    Zoned,
+};
+use polars::prelude::*;
+
+impl<'a> IterFromColumn<'a> for Zoned {
+    type RawInner = (i64, tz::TimeZone);
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<(i64, tz::TimeZone)>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_zoned_iter(column)
+    }
+
+    fn get_value(polars_value: Option<(i64, tz::TimeZone)>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let (timestamp, time_zone) =
+            polars_value.ok_or_else(|| <Zoned as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_zoned(timestamp, time_zone, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<Zoned> {
+    type RawInner = (i64, tz::TimeZone);
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<(i64, tz::TimeZone)>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_zoned_iter(column)
+    }
+
+    fn get_value(polars_value: Option<(i64, tz::TimeZone)>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|(timestamp, time_zone)| create_zoned(timestamp, time_zone, column_name, dtype))
+            .transpose()
+    }
+}
+
+fn create_zoned_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<(i64, tz::TimeZone)>> + 'a> {
+    create_zoned_datetime_iter(
+        column,
+        "jiff::Zoned",
+        "jiff::Timestamp or jiff::civil::DateTime",
+        resolve_time_zone,
+    )
+}
+
+fn resolve_time_zone(time_zone: &str, column_name: &str) -> PolarsResult<tz::TimeZone> {
+    if time_zone == TimeZone::UTC.as_str() {
+        return Ok(tz::TimeZone::UTC);
+    }
+
+    if let Some(offset) = parse_fixed_offset(time_zone).and_then(|seconds| Offset::from_seconds(seconds).ok()) {
+        return Ok(tz::TimeZone::fixed(offset));
+    }
+
+    tz::TimeZone::get(time_zone)
+        .map_err(|err| polars_err!(ComputeError: "Unknown time zone '{time_zone}' in column '{column_name}': {err}"))
+}
+
+fn create_zoned(timestamp: i64, time_zone: tz::TimeZone, column_name: &str, dtype: &DataType) -> PolarsResult<Zoned> {
+    create_timestamp(timestamp, column_name, dtype).map(|timestamp| timestamp.to_zoned(time_zone))
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn create_zoned_column(name: &str, values: Vec<Option<i64>>, time_zone: &str) -> Column {
+        let time_zone = TimeZone::opt_try_new(Some(time_zone)).unwrap();
+        Int64Chunked::new(name.into(), &values)
+            .into_datetime(TimeUnit::Milliseconds, time_zone)
+            .into_column()
+    }
+
+    #[test]
+    fn zoned_should_use_column_time_zone() {
+        let column_berlin = create_zoned_column(
+            "berlin",
+            vec![Some(1732122821000), Some(1720000000000)],
+            "Europe/Berlin",
+        );
+        let column_offset = create_zoned_column("offset", vec![None, Some(1732122821000)], "-05:30");
+
+        let df = DataFrame::new(2, vec![column_berlin, column_offset]).unwrap();
+
+        #[derive(Debug, PartialEq, FromDataFrameRow)]
+        struct TestRow {
+            berlin: Zoned,
+            offset: Option<Zoned>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|row| row.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    berlin: "2024-11-20T18:13:41+01:00[Europe/Berlin]".parse().unwrap(),
+                    offset: None,
+                },
+                TestRow {
+                    berlin: "2024-07-03T11:46:40+02:00[Europe/Berlin]".parse().unwrap(),
+                    offset: Some("2024-11-20T11:43:41-05:30[-05:30]".parse().unwrap()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn zoned_should_error_on_column_without_time_zone() {
+        let column = Column::new("dt".into(), vec![1732122821000i64])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let err_msg = df.scalar_iter::<Zoned>("dt").err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            "Cannot get jiff::Zoned from column 'dt' without time zone. Use jiff::Timestamp or jiff::civil::DateTime instead."
+        );
+    }
+}
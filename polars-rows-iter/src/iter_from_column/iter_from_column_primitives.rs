@@ -7,10 +7,13 @@ iter_from_column_for_type!(i8);
 iter_from_column_for_type!(i16);
 // i32 has specific definition
 // i64 has specific definition
+// i128 has specific definition
 iter_from_column_for_type!(u8);
 iter_from_column_for_type!(u16);
 iter_from_column_for_type!(u32);
 iter_from_column_for_type!(u64);
+#[cfg(feature = "dtype-u128")]
+iter_from_column_for_type!(u128);
 iter_from_column_for_type!(f32);
 iter_from_column_for_type!(f64);
 
@@ -33,6 +36,8 @@ mod tests {
     create_rows_iter_test_for_chunked_type!(u16_test, u16, u16, DataType::UInt16, ROW_COUNT);
     create_rows_iter_test_for_chunked_type!(u32_test, u32, u32, DataType::UInt32, ROW_COUNT);
     create_rows_iter_test_for_chunked_type!(u64_test, u64, u64, DataType::UInt64, ROW_COUNT);
+    #[cfg(feature = "dtype-u128")]
+    create_rows_iter_test_for_chunked_type!(u128_test, u128, u128, DataType::UInt128, ROW_COUNT);
     create_rows_iter_test_for_chunked_type!(f32_test, f32, f32, DataType::Float32, ROW_COUNT);
     create_rows_iter_test_for_chunked_type!(f64_test, f64, f64, DataType::Float64, ROW_COUNT);
 }
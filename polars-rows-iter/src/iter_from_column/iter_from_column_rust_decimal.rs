@@ -0,0 +1,131 @@
+use super::iter_from_column_i128::create_iter;
+use crate::*;
+use polars::prelude::*;
+use rust_decimal::Decimal;
+
+impl<'a> IterFromColumn<'a> for Decimal {
+    type RawInner = i128;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i128>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_iter(column)
+    }
+
+    fn get_value(polars_value: Option<i128>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let mantissa =
+            polars_value.ok_or_else(|| <Decimal as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_decimal(mantissa, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<Decimal> {
+    type RawInner = i128;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i128>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_iter(column)
+    }
+
+    fn get_value(polars_value: Option<i128>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|mantissa| create_decimal(mantissa, column_name, dtype))
+            .transpose()
+    }
+}
+
+fn create_decimal(mantissa: i128, column_name: &str, dtype: &DataType) -> PolarsResult<Decimal> {
+    let scale = if let DataType::Decimal(_, scale) = dtype {
+        *scale
+    } else {
+        return Err(polars_err!(SchemaMismatch: "Unable to create rust_decimal::Decimal from DataType: {dtype}"));
+    };
+
+    u32::try_from(scale)
+        .ok()
+        .and_then(|scale| Decimal::try_from_i128_with_scale(mantissa, scale).ok())
+        .ok_or_else(|| polars_err!(OutOfBounds: "Value {mantissa} with scale {scale} in column {column_name} is out of range for rust_decimal::Decimal"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn decimal_should_be_read_with_column_scale() {
+        let column_id = Column::new("id".into(), vec![1i32, 2, 3]);
+        let column_amount = Int128Chunked::from_slice("amount".into(), &[12345, -1, 100])
+            .into_decimal_unchecked(10, 2)
+            .into_column();
+        let column_amount_opt = Int128Chunked::from_slice_options("amount_opt".into(), &[None, Some(5), Some(-123456)])
+            .into_decimal_unchecked(20, 4)
+            .into_column();
+
+        let df = DataFrame::new(3, vec![column_id, column_amount, column_amount_opt]).unwrap();
+
+        #[derive(Debug, PartialEq, FromDataFrameRow)]
+        struct TestRow {
+            id: i32,
+            amount: Decimal,
+            amount_opt: Option<Decimal>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|row| row.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    id: 1,
+                    amount: Decimal::new(12345, 2),
+                    amount_opt: None
+                },
+                TestRow {
+                    id: 2,
+                    amount: Decimal::new(-1, 2),
+                    amount_opt: Some(Decimal::new(5, 4))
+                },
+                TestRow {
+                    id: 3,
+                    amount: Decimal::new(100, 2),
+                    amount_opt: Some(Decimal::new(-123456, 4))
+                },
+            ]
+        );
+        assert_eq!(rows[0].amount.to_string(), "123.45");
+    }
+
+    #[test]
+    fn decimal_should_fail_for_values_out_of_range() {
+        let column_name = "amount";
+        let column = Int128Chunked::from_slice(column_name.into(), &[i128::MAX / 10])
+            .into_decimal_unchecked(38, 0)
+            .into_column();
+
+        let df = DataFrame::new(1, vec![column]).unwrap();
+
+        let result = df
+            .scalar_iter::<Decimal>(column_name)
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decimal_should_fail_for_non_decimal_column() {
+        let column = Column::new("amount".into(), vec![1.5f64]);
+        let df = DataFrame::new(1, vec![column]).unwrap();
+
+        assert!(df.scalar_iter::<Decimal>("amount").is_err());
+    }
+}
@@ -40,6 +40,13 @@ pub(crate) fn create_series_iter<'a>(column: &'a Column) -> PolarsResult<impl It
                 .amortized_iter()
                 .map(|opt| opt.map(|series| series.deep_clone())),
         ),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(_, _) => Box::new(
+            column
+                .array()?
+                .amortized_iter()
+                .map(|opt| opt.map(|series| series.deep_clone())),
+        ),
         dtype => {
             return Err(
                 polars_err!(SchemaMismatch: "Cannot get Series from column '{column_name}' with dtype: {dtype}"),
@@ -0,0 +1,230 @@
+use crate::*;
+use polars::prelude::*;
+
+macro_rules! impl_iter_from_column_for_slice {
+    ($native:ty, $polars_type:ty) => {
+        impl<'a> IterFromColumn<'a> for &'a [$native] {
+            type RawInner = &'a [$native];
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                create_slice_iter::<$polars_type>(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                column_name: &str,
+                _dtype: &DataType,
+            ) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                polars_value.ok_or_else(|| <Self as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
+            }
+        }
+
+        impl<'a> IterFromColumn<'a> for Option<&'a [$native]> {
+            type RawInner = &'a [$native];
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                create_slice_iter::<$polars_type>(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                _column_name: &str,
+                _dtype: &DataType,
+            ) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                Ok(polars_value)
+            }
+        }
+    };
+}
+
+impl_iter_from_column_for_slice!(i8, Int8Type);
+impl_iter_from_column_for_slice!(i16, Int16Type);
+impl_iter_from_column_for_slice!(i32, Int32Type);
+impl_iter_from_column_for_slice!(i64, Int64Type);
+// &[u8] is handled together with binary columns
+impl_iter_from_column_for_slice!(u16, UInt16Type);
+impl_iter_from_column_for_slice!(u32, UInt32Type);
+impl_iter_from_column_for_slice!(u64, UInt64Type);
+impl_iter_from_column_for_slice!(f32, Float32Type);
+impl_iter_from_column_for_slice!(f64, Float64Type);
+
+/// Borrows every list of a primitive list column directly from the values buffer of its chunk.
+///
+/// A slice cannot represent missing inner values, so an error is returned if any list contains nulls.
+/// Use `Vec<Option<T>>` for such columns, which copies the values instead.
+pub(crate) fn create_slice_iter<'a, T>(
+    column: &'a Column,
+) -> PolarsResult<impl Iterator<Item = Option<&'a [T::Native]>> + 'a>
+where
+    T: PolarsNumericType,
+{
+    let column_name = column.name().as_str();
+    let expected_inner_dtype = T::get_static_dtype();
+
+    match column.dtype() {
+        DataType::List(inner_dtype) if **inner_dtype == expected_inner_dtype => {}
+        dtype => {
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get &[{expected_inner_dtype}] from column '{column_name}' with dtype: {dtype}"),
+            )
+        }
+    }
+
+    let chunks = column
+        .list()?
+        .downcast_iter()
+        .map(|list_array| {
+            let values = list_array
+                .values()
+                .as_any()
+                .downcast_ref::<T::Array>()
+                .ok_or_else(|| polars_err!(ComputeError: "Unexpected physical type of list values in column '{column_name}'"))?;
+
+            let offsets = list_array.offsets();
+            let start = *offsets.first() as usize;
+            let end = *offsets.last() as usize;
+
+            if let Some(validity) = values.validity() {
+                if validity.null_count_range(start, end - start) > 0 {
+                    return Err(
+                        polars_err!(ComputeError: "Cannot borrow list values of column '{column_name}' as slices because they contain null values. Use Vec<Option<T>> instead."),
+                    );
+                }
+            }
+
+            let values = values.values().as_slice();
+            let validity = list_array.validity();
+
+            let rows = offsets.windows(2).enumerate().map(move |(index, window)| {
+                let is_valid = validity.is_none_or(|validity| validity.get_bit(index));
+                is_valid.then(|| &values[window[0] as usize..window[1] as usize])
+            });
+
+            Ok(rows)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(chunks.into_iter().flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use itertools::Itertools;
+    use polars::prelude::*;
+
+    #[test]
+    fn slice_f64_rows_iter_test() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1.0f64, 2.0, 3.0]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &[4.0f64, 5.0]);
+        let s3 = Series::new(PlSmallStr::EMPTY, &[6.0f64]);
+
+        let col = Column::new("col".into(), vec![s1.clone(), s2.clone(), s3.clone()]);
+        let col_opt = Column::new("col_opt".into(), vec![Some(s1), None, Some(s3)]);
+
+        let df = DataFrame::new(3, vec![col, col_opt]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow<'a> {
+            col: &'a [f64],
+            col_opt: Option<&'a [f64]>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    col: &[1.0, 2.0, 3.0],
+                    col_opt: Some(&[1.0, 2.0, 3.0])
+                },
+                TestRow {
+                    col: &[4.0, 5.0],
+                    col_opt: None
+                },
+                TestRow {
+                    col: &[6.0],
+                    col_opt: Some(&[6.0])
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn slice_i32_from_multiple_sliced_chunks() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1i32, 2]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &[3i32]);
+        let s3 = Series::new(PlSmallStr::EMPTY, &[4i32, 5, 6]);
+
+        let mut col = Column::new("col".into(), vec![s1.clone(), s2.clone()]);
+        col.append(&Column::new("col".into(), vec![s3, s1, s2])).unwrap();
+        let col = col.slice(1, 3);
+
+        let df = DataFrame::new(3, vec![col]).unwrap();
+
+        let values = df
+            .scalar_iter("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<&[i32]>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![&[3][..], &[4, 5, 6], &[1, 2]]);
+    }
+
+    #[test]
+    fn slice_u8_from_list_column() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1u8, 2]);
+
+        let col = Column::new("col".into(), vec![Some(s1), None]);
+        let df = DataFrame::new(2, vec![col]).unwrap();
+
+        let values = df
+            .scalar_iter("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Option<&[u8]>>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![Some(&[1u8, 2][..]), None]);
+    }
+
+    #[test]
+    fn slice_errors_on_inner_null_values() {
+        let column_name = "col";
+
+        let s1 = Series::new(PlSmallStr::EMPTY, &[Some(1i64), None]);
+
+        let col = Column::new(column_name.into(), vec![s1]);
+        let df = DataFrame::new(1, vec![col]).unwrap();
+
+        let err_msg = df.scalar_iter::<&[i64]>(column_name).err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            format!("Cannot borrow list values of column '{column_name}' as slices because they contain null values. Use Vec<Option<T>> instead.")
+        );
+    }
+
+    #[test]
+    fn slice_errors_on_inner_dtype_mismatch() {
+        let column_name = "col";
+
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1i64, 2]);
+
+        let col = Column::new(column_name.into(), vec![s1]);
+        let df = DataFrame::new(1, vec![col]).unwrap();
+
+        let err_msg = df.scalar_iter::<&[i32]>(column_name).err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            format!("Cannot get &[i32] from column '{column_name}' with dtype: list[i64]")
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use crate::*;
+use polars::prelude::*;
+use std::collections::HashMap;
+
+/// Row structs without a lifetime can be read from `Struct` columns, which makes them usable
+/// as field types of other row structs.
+impl<'a, T> IterFromColumn<'a> for T
+where
+    T: for<'inner> FromDataFrameRow<'inner> + 'a,
+{
+    type RawInner = PolarsResult<T>;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<PolarsResult<T>>> + 'a> {
+        create_row_struct_iter(column)
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<PolarsResult<T>>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value.ok_or_else(|| <T as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?
+    }
+}
+
+impl<T> NullableIterFromColumn for T where T: for<'inner> FromDataFrameRow<'inner> {}
+
+fn create_row_struct_iter<'a, T>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<PolarsResult<T>>> + 'a>
+where
+    T: for<'inner> FromDataFrameRow<'inner> + 'a,
+{
+    create_struct_iter(column, |_column_name, fields| {
+        Ok(T::from_dataframe(fields, HashMap::new())?.collect())
+    })
+}
+
+macro_rules! impl_iter_from_column_for_tuple {
+    ($($ty:ident $idx:tt),+) => {
+        impl<'a, $($ty),+> IterFromColumn<'a> for ($($ty,)+)
+        where
+            $($ty: for<'inner> IterFromColumn<'inner> + 'a,)+
+        {
+            type RawInner = PolarsResult<Self>;
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<PolarsResult<Self>>> + 'a> {
+                create_tuple_iter(column)
+            }
+
+            #[inline]
+            fn get_value(polars_value: Option<PolarsResult<Self>>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                polars_value.ok_or_else(|| <Self as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?
+            }
+        }
+
+        impl<'a, $($ty),+> IterFromColumn<'a> for Option<($($ty,)+)>
+        where
+            $($ty: for<'inner> IterFromColumn<'inner> + 'a,)+
+        {
+            type RawInner = PolarsResult<($($ty,)+)>;
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                create_tuple_iter(column)
+            }
+
+            #[inline]
+            fn get_value(polars_value: Option<Self::RawInner>, _column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                polars_value.transpose()
+            }
+        }
+
+        impl<$($ty),+> TupleFromStructFields for ($($ty,)+)
+        where
+            $($ty: for<'inner> IterFromColumn<'inner>,)+
+        {
+            const LEN: usize = [$(stringify!($ty)),+].len();
+
+            fn collect_rows(fields: &DataFrame) -> PolarsResult<Vec<PolarsResult<Self>>> {
+                let columns = fields.columns();
+                let mut values = ($(collect_field_values::<$ty>(&columns[$idx])?,)+);
+
+                let rows = (0..fields.height())
+                    .map(|_| Ok(($(values.$idx.next().expect("struct fields have the same length")?,)+)))
+                    .collect();
+
+                Ok(rows)
+            }
+        }
+    };
+}
+
+impl_iter_from_column_for_tuple!(T0 0);
+impl_iter_from_column_for_tuple!(T0 0, T1 1);
+impl_iter_from_column_for_tuple!(T0 0, T1 1, T2 2);
+impl_iter_from_column_for_tuple!(T0 0, T1 1, T2 2, T3 3);
+impl_iter_from_column_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_iter_from_column_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+impl_iter_from_column_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+impl_iter_from_column_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+impl_iter_from_column_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8);
+impl_iter_from_column_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9);
+
+trait TupleFromStructFields: Sized {
+    const LEN: usize;
+    fn collect_rows(fields: &DataFrame) -> PolarsResult<Vec<PolarsResult<Self>>>;
+}
+
+fn create_tuple_iter<'a, T>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<PolarsResult<T>>> + 'a>
+where
+    T: TupleFromStructFields + 'a,
+{
+    create_struct_iter(column, |column_name, fields| {
+        let (tuple_len, field_count) = (T::LEN, fields.width());
+        if tuple_len != field_count {
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get tuple of size {tuple_len} from column '{column_name}' with {field_count} struct fields"),
+            );
+        }
+
+        T::collect_rows(fields)
+    })
+}
+
+fn collect_field_values<T>(column: &Column) -> PolarsResult<std::vec::IntoIter<PolarsResult<T>>>
+where
+    T: for<'inner> IterFromColumn<'inner>,
+{
+    let column_name = column.name().as_str();
+    let column_dtype = column.dtype();
+
+    let values = <T as IterFromColumn>::create_iter(column)?
+        .map(|v| <T as IterFromColumn>::get_value(v, column_name, column_dtype))
+        .collect::<Vec<_>>();
+
+    Ok(values.into_iter())
+}
+
+/// Struct fields are unnested into a temporary DataFrame, so the values have to be collected
+/// before the iterator is returned. Rows with a null struct value are yielded as `None`.
+#[cfg(feature = "dtype-struct")]
+fn create_struct_iter<'a, T, F>(
+    column: &'a Column,
+    collect_rows: F,
+) -> PolarsResult<impl Iterator<Item = Option<PolarsResult<T>>> + 'a>
+where
+    T: 'a,
+    F: FnOnce(&str, &DataFrame) -> PolarsResult<Vec<PolarsResult<T>>>,
+{
+    let column_name = column.name().as_str();
+    let fields = match column.dtype() {
+        DataType::Struct(_) => column.struct_()?.clone().unnest(),
+        dtype => {
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get struct from column '{column_name}' with dtype: {dtype}"),
+            )
+        }
+    };
+
+    let rows = collect_rows(column_name, &fields)?;
+    let is_valid = column
+        .is_not_null()
+        .iter()
+        .map(|v| v.unwrap_or(false))
+        .collect::<Vec<_>>();
+
+    Ok(is_valid
+        .into_iter()
+        .zip(rows)
+        .map(|(is_valid, row)| is_valid.then_some(row)))
+}
+
+#[cfg(not(feature = "dtype-struct"))]
+fn create_struct_iter<'a, T, F>(
+    column: &'a Column,
+    _collect_rows: F,
+) -> PolarsResult<std::iter::Empty<Option<PolarsResult<T>>>>
+where
+    T: 'a,
+    F: FnOnce(&str, &DataFrame) -> PolarsResult<Vec<PolarsResult<T>>>,
+{
+    let column_name = column.name().as_str();
+    let dtype = column.dtype();
+    Err(
+        polars_err!(SchemaMismatch: "Cannot get struct from column '{column_name}' with dtype '{dtype}'.\
+                                     Make sure to enable 'dtype-struct' feature for 'Struct' dtypes."),
+    )
+}
+
+#[cfg(all(test, feature = "dtype-struct"))]
+mod tests {
+    use crate::*;
+    use itertools::Itertools;
+    use polars::prelude::*;
+
+    fn create_point_column(name: &str) -> Column {
+        let x = Column::new("x".into(), [Some(1.0f64), Some(2.0), None]);
+        let y = Column::new("y".into(), [4.0f64, 5.0, 6.0]);
+
+        StructChunked::from_columns(name.into(), 3, &[x, y])
+            .unwrap()
+            .into_column()
+    }
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Point {
+        #[column("x")]
+        px: Option<f64>,
+        y: f64,
+    }
+
+    #[test]
+    fn nested_row_struct_rows_iter_test() {
+        let id = Column::new("id".into(), [1i32, 2, 3]);
+        let df = DataFrame::new(3, vec![id, create_point_column("point")]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            id: i32,
+            point: Point,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    id: 1,
+                    point: Point { px: Some(1.0), y: 4.0 }
+                },
+                TestRow {
+                    id: 2,
+                    point: Point { px: Some(2.0), y: 5.0 }
+                },
+                TestRow {
+                    id: 3,
+                    point: Point { px: None, y: 6.0 }
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_row_struct_with_outer_nulls_scalar_iter_test() {
+        let point = create_point_column("point");
+        let mask = BooleanChunked::new("mask".into(), [true, false, true]);
+        let nulls = Column::full_null("point".into(), 3, point.dtype());
+        let point = point.zip_with(&mask, &nulls).unwrap();
+        let df = DataFrame::new(3, vec![point]).unwrap();
+
+        let values = df
+            .scalar_iter("point")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Option<Point>>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![
+                Some(Point { px: Some(1.0), y: 4.0 }),
+                None,
+                Some(Point { px: None, y: 6.0 })
+            ]
+        );
+
+        let err_msg = df
+            .scalar_iter("point")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Point>>>()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            "Found unexpected None/null value in column 'point' with mandatory values!"
+        );
+    }
+
+    #[test]
+    fn nested_row_struct_errors_on_missing_field() {
+        let x = Column::new("x".into(), [1.0f64]);
+        let point = StructChunked::from_columns("point".into(), 1, &[x])
+            .unwrap()
+            .into_column();
+        let df = DataFrame::new(1, vec![point]).unwrap();
+
+        assert!(df.scalar_iter::<Point>("point").is_err());
+    }
+
+    #[test]
+    fn tuple_from_struct_rows_iter_test() {
+        let df = DataFrame::new(3, vec![create_point_column("point")]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            point: (Option<f64>, f64),
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    point: (Some(1.0), 4.0)
+                },
+                TestRow {
+                    point: (Some(2.0), 5.0)
+                },
+                TestRow { point: (None, 6.0) },
+            ]
+        );
+    }
+
+    #[test]
+    fn tuple_from_struct_errors_on_inner_null() {
+        let df = DataFrame::new(3, vec![create_point_column("point")]).unwrap();
+
+        let err_msg = df
+            .scalar_iter("point")
+            .unwrap()
+            .collect::<PolarsResult<Vec<(f64, f64)>>>()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            "Found unexpected None/null value in column 'x' with mandatory values!"
+        );
+    }
+
+    #[test]
+    fn tuple_from_struct_errors_on_size_mismatch() {
+        let df = DataFrame::new(3, vec![create_point_column("point")]).unwrap();
+
+        let err_msg = df.scalar_iter::<(f64, f64, f64)>("point").err().unwrap().to_string();
+
+        assert_eq!(
+            err_msg,
+            "Cannot get tuple of size 3 from column 'point' with 2 struct fields"
+        );
+    }
+}
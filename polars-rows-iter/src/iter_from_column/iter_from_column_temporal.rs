@@ -0,0 +1,226 @@
+//! Unit conversions shared by the implementations for the different date and time crates.
+
+use polars::prelude::*;
+
+pub(crate) const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub(crate) const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Splits a `Datetime` or `Duration` value into whole seconds and the non-negative nanoseconds of the second.
+pub(crate) fn split_seconds(value: i64, time_unit: TimeUnit) -> (i64, u32) {
+    let units_per_second = match time_unit {
+        TimeUnit::Nanoseconds => 1_000_000_000,
+        TimeUnit::Microseconds => 1_000_000,
+        TimeUnit::Milliseconds => 1_000,
+    };
+    let nanoseconds_per_unit = NANOSECONDS_PER_SECOND / units_per_second;
+
+    let seconds = value.div_euclid(units_per_second);
+    let nanoseconds = value.rem_euclid(units_per_second) * nanoseconds_per_unit;
+
+    (seconds, nanoseconds as u32)
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+/// Splits the nanoseconds since midnight of a `Time` value into hour, minute, second and nanosecond.
+pub(crate) fn split_time_of_day(nanoseconds: i64) -> Option<(u32, u32, u32, u32)> {
+    if !(0..SECONDS_PER_DAY * NANOSECONDS_PER_SECOND).contains(&nanoseconds) {
+        return None;
+    }
+
+    let seconds = (nanoseconds / NANOSECONDS_PER_SECOND) as u32;
+    let nanosecond = (nanoseconds % NANOSECONDS_PER_SECOND) as u32;
+
+    Some((seconds / 3600, seconds % 3600 / 60, seconds % 60, nanosecond))
+}
+
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+/// Parses fixed offset time zones like `+02:00` or `-0530` into seconds east of UTC.
+pub(crate) fn parse_fixed_offset(time_zone: &str) -> Option<i32> {
+    let (sign, offset) = match time_zone.as_bytes().first()? {
+        b'+' => (1, &time_zone[1..]),
+        b'-' => (-1, &time_zone[1..]),
+        _ => return None,
+    };
+
+    if !offset.is_ascii() {
+        return None;
+    }
+
+    let (hours, minutes) = match offset.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if offset.len() == 4 => offset.split_at(2),
+        None => (offset, "00"),
+    };
+
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+
+    let hours = hours.parse::<u8>().ok().filter(|hours| *hours <= 23)? as i32;
+    let minutes = minutes.parse::<u8>().ok().filter(|minutes| *minutes <= 59)? as i32;
+
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub(crate) fn get_datetime_time_unit(dtype: &DataType, type_name: &str) -> PolarsResult<TimeUnit> {
+    match dtype {
+        DataType::Datetime(time_unit, _) => Ok(*time_unit),
+        dtype => Err(polars_err!(SchemaMismatch: "Unable to create {type_name} from DataType: {dtype}")),
+    }
+}
+
+pub(crate) fn get_duration_time_unit(dtype: &DataType, type_name: &str) -> PolarsResult<TimeUnit> {
+    match dtype {
+        DataType::Duration(time_unit) => Ok(*time_unit),
+        dtype => Err(polars_err!(SchemaMismatch: "Unable to create {type_name} from DataType: {dtype}")),
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub(crate) fn create_date_iter<'a>(
+    column: &'a Column,
+    type_name: &str,
+) -> PolarsResult<impl Iterator<Item = Option<i32>> + 'a> {
+    let column_name = column.name().as_str();
+    match column.dtype() {
+        DataType::Date => Ok(column.date()?.phys.iter()),
+        dtype => {
+            Err(polars_err!(SchemaMismatch: "Cannot get {type_name} from column '{column_name}' with dtype : {dtype}"))
+        }
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub(crate) fn create_time_iter<'a>(
+    column: &'a Column,
+    type_name: &str,
+) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+    let column_name = column.name().as_str();
+    match column.dtype() {
+        DataType::Time => Ok(column.as_materialized_series().time()?.phys.iter()),
+        dtype => {
+            Err(polars_err!(SchemaMismatch: "Cannot get {type_name} from column '{column_name}' with dtype : {dtype}"))
+        }
+    }
+}
+
+pub(crate) fn create_duration_iter<'a>(
+    column: &'a Column,
+    type_name: &str,
+) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+    let column_name = column.name().as_str();
+    match column.dtype() {
+        DataType::Duration(_) => Ok(column.duration()?.phys.iter()),
+        dtype => {
+            Err(polars_err!(SchemaMismatch: "Cannot get {type_name} from column '{column_name}' with dtype : {dtype}"))
+        }
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+/// Accepts any `Datetime` column, polars stores the values relative to the unix epoch in UTC regardless of the time zone.
+pub(crate) fn create_instant_iter<'a>(
+    column: &'a Column,
+    type_name: &str,
+) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+    let column_name = column.name().as_str();
+    match column.dtype() {
+        DataType::Datetime(_, _) => Ok(column.datetime()?.phys.iter()),
+        dtype => {
+            Err(polars_err!(SchemaMismatch: "Cannot get {type_name} from column '{column_name}' with dtype : {dtype}"))
+        }
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+/// Zoned columns are rejected, since it is ambiguous whether the UTC or the local wall clock time is wanted.
+pub(crate) fn create_naive_datetime_iter<'a>(
+    column: &'a Column,
+    type_name: &str,
+    zoned_type_names: &str,
+) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+    let column_name = column.name().as_str();
+    match column.dtype() {
+        DataType::Datetime(_, Some(time_zone)) if *time_zone != TimeZone::UTC => Err(
+            polars_err!(SchemaMismatch: "Cannot get {type_name} from column '{column_name}' with time zone '{time_zone}'. \
+                                         Use {zoned_type_names} instead."),
+        ),
+        _ => create_instant_iter(column, type_name),
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+/// Creates an iterator over the timestamps of a zoned datetime column, each paired with the column time zone
+/// that is resolved only once.
+pub(crate) fn create_zoned_datetime_iter<'a, Z, F>(
+    column: &'a Column,
+    type_name: &str,
+    naive_type_names: &str,
+    resolve_time_zone: F,
+) -> PolarsResult<impl Iterator<Item = Option<(i64, Z)>> + 'a>
+where
+    Z: Clone + 'a,
+    F: FnOnce(&str, &str) -> PolarsResult<Z>,
+{
+    let column_name = column.name().as_str();
+    let zone = match column.dtype() {
+        DataType::Datetime(_, Some(time_zone)) => resolve_time_zone(time_zone.as_str(), column_name)?,
+        DataType::Datetime(_, None) => {
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get {type_name} from column '{column_name}' without time zone. Use {naive_type_names} instead."),
+            )
+        }
+        dtype => {
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get {type_name} from column '{column_name}' with dtype : {dtype}"),
+            )
+        }
+    };
+
+    Ok(column
+        .datetime()?
+        .phys
+        .iter()
+        .map(move |timestamp| timestamp.map(|timestamp| (timestamp, zone.clone()))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_seconds_should_respect_time_units() {
+        assert_eq!(split_seconds(1_500, TimeUnit::Milliseconds), (1, 500_000_000));
+        assert_eq!(split_seconds(1_500, TimeUnit::Microseconds), (0, 1_500_000));
+        assert_eq!(split_seconds(1_500, TimeUnit::Nanoseconds), (0, 1_500));
+    }
+
+    #[test]
+    fn split_seconds_should_keep_nanoseconds_positive_before_epoch() {
+        assert_eq!(split_seconds(-1, TimeUnit::Milliseconds), (-1, 999_000_000));
+        assert_eq!(
+            split_seconds(i64::MIN, TimeUnit::Nanoseconds),
+            (-9_223_372_037, 145_224_192)
+        );
+    }
+
+    #[cfg(any(feature = "chrono", feature = "jiff"))]
+    #[test]
+    fn parse_fixed_offset_should_accept_signed_hours_and_minutes() {
+        assert_eq!(parse_fixed_offset("+02:00"), Some(7200));
+        assert_eq!(parse_fixed_offset("-0530"), Some(-19800));
+        assert_eq!(parse_fixed_offset("+01"), Some(3600));
+        assert_eq!(parse_fixed_offset("Europe/Berlin"), None);
+        assert_eq!(parse_fixed_offset("+24:00"), None);
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+    #[test]
+    fn split_time_of_day_should_reject_values_outside_of_day() {
+        assert_eq!(split_time_of_day(45_296_000_000_123), Some((12, 34, 56, 123)));
+        assert_eq!(split_time_of_day(-1), None);
+        assert_eq!(split_time_of_day(SECONDS_PER_DAY * NANOSECONDS_PER_SECOND), None);
+    }
+}
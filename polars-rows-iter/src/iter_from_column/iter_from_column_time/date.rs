@@ -0,0 +1,121 @@
+use crate::{iter_from_column::iter_from_column_temporal::create_date_iter, *};
+use polars::prelude::*;
+use time::Date;
+
+/// Julian day number of the unix epoch 1970-01-01
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+impl<'a> IterFromColumn<'a> for Date {
+    type RawInner = i32;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i32>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_date_iter(column, "time::Date")
+    }
+
+    fn get_value(polars_value: Option<i32>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let days =
+            polars_value.ok_or_else(|| <Date as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_date(days, column_name)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<Date> {
+    type RawInner = i32;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i32>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_date_iter(column, "time::Date")
+    }
+
+    fn get_value(polars_value: Option<i32>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value.map(|days| create_date(days, column_name)).transpose()
+    }
+}
+
+fn create_date(days: i32, column_name: &str) -> PolarsResult<Date> {
+    days.checked_add(UNIX_EPOCH_JULIAN_DAY)
+        .and_then(|julian_day| Date::from_julian_day(julian_day).ok())
+        .ok_or_else(|| polars_err!(OutOfBounds: "Value {days} in column {column_name} is not a valid date"))
+}
+
+#[cfg(test)]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+    use time::macros::date;
+
+    #[test]
+    fn date_test() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Date;
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let to_date = |days: i32| date!(1970 - 01 - 01) + time::Duration::days(days as i64);
+        let col_values = col
+            .date()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| to_date(v.unwrap()))
+            .collect_vec();
+        let col_opt_values = col_opt
+            .date()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| v.map(to_date))
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: Date,
+            col_opt: Option<Date>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+
+    #[test]
+    fn date_should_be_read_before_and_after_epoch() {
+        let column = Column::new("date".into(), vec![-1i32, 0, 20_000])
+            .cast(&DataType::Date)
+            .unwrap();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let values = df
+            .scalar_iter("date")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Date>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![date!(1969 - 12 - 31), date!(1970 - 01 - 01), date!(2024 - 10 - 04)]
+        );
+    }
+}
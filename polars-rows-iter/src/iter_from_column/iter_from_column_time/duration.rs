@@ -0,0 +1,106 @@
+use super::create_duration;
+use crate::{iter_from_column::iter_from_column_temporal::create_duration_iter, *};
+use polars::prelude::*;
+use time::Duration;
+
+impl<'a> IterFromColumn<'a> for Duration {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_duration_iter(column, "time::Duration")
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let value =
+            polars_value.ok_or_else(|| <Duration as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_duration(value, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<Duration> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_duration_iter(column, "time::Duration")
+    }
+
+    fn get_value(polars_value: Option<i64>, _column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value.map(|value| create_duration(value, dtype)).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+
+    fn duration_test(time_unit: TimeUnit, to_duration: fn(i64) -> Duration) {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Duration(time_unit);
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let col_values = col
+            .duration()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| to_duration(v.unwrap()))
+            .collect_vec();
+        let col_opt_values = col_opt
+            .duration()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| v.map(to_duration))
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: Duration,
+            col_opt: Option<Duration>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+
+    #[test]
+    fn duration_millis_test() {
+        duration_test(TimeUnit::Milliseconds, Duration::milliseconds);
+    }
+
+    #[test]
+    fn duration_micros_test() {
+        duration_test(TimeUnit::Microseconds, Duration::microseconds);
+    }
+
+    #[test]
+    fn duration_nanos_test() {
+        duration_test(TimeUnit::Nanoseconds, Duration::nanoseconds);
+    }
+}
@@ -0,0 +1,23 @@
+mod date;
+mod duration;
+mod offsetdatetime;
+mod primitivedatetime;
+mod time;
+
+use crate::iter_from_column::iter_from_column_temporal::{
+    get_datetime_time_unit, get_duration_time_unit, split_seconds, NANOSECONDS_PER_SECOND,
+};
+use ::time::{Duration, OffsetDateTime};
+use polars::prelude::*;
+
+fn create_utc_datetime(timestamp: i64, column_name: &str, dtype: &DataType) -> PolarsResult<OffsetDateTime> {
+    let (seconds, nanoseconds) = split_seconds(timestamp, get_datetime_time_unit(dtype, "time::OffsetDateTime")?);
+
+    OffsetDateTime::from_unix_timestamp_nanos(seconds as i128 * NANOSECONDS_PER_SECOND as i128 + nanoseconds as i128)
+        .map_err(|_| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid timestamp"))
+}
+
+fn create_duration(value: i64, dtype: &DataType) -> PolarsResult<Duration> {
+    let (seconds, nanoseconds) = split_seconds(value, get_duration_time_unit(dtype, "time::Duration")?);
+    Ok(Duration::new(seconds, nanoseconds as i32))
+}
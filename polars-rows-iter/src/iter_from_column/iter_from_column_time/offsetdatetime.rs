@@ -0,0 +1,134 @@
+use super::create_utc_datetime;
+use crate::{iter_from_column::iter_from_column_temporal::create_instant_iter, *};
+use polars::prelude::*;
+use time::OffsetDateTime;
+
+impl<'a> IterFromColumn<'a> for OffsetDateTime {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let timestamp = polars_value
+            .ok_or_else(|| <OffsetDateTime as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_utc_datetime(timestamp, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<OffsetDateTime> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|timestamp| create_utc_datetime(timestamp, column_name, dtype))
+            .transpose()
+    }
+}
+
+/// Values of zoned columns are returned with UTC offset, since `time` has no time zone database.
+fn create_datetime_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+    create_instant_iter(column, "time::OffsetDateTime")
+}
+
+#[cfg(test)]
+mod tests {
+    const ROW_COUNT: usize = 64;
+
+    use super::*;
+    use itertools::{izip, Itertools};
+    use rand::{rngs::StdRng, SeedableRng};
+    use testing::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn offset_datetime_test() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let height = ROW_COUNT;
+        let dtype = DataType::Datetime(TimeUnit::Nanoseconds, None);
+
+        let col = create_column("col", &dtype, false, height, &mut rng);
+        let col_opt = create_column("col_opt", &dtype, true, height, &mut rng);
+
+        let to_datetime = |ns: i64| OffsetDateTime::from_unix_timestamp_nanos(ns as i128).unwrap();
+        let col_values = col
+            .datetime()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| to_datetime(v.unwrap()))
+            .collect_vec();
+        let col_opt_values = col_opt
+            .datetime()
+            .unwrap()
+            .phys
+            .iter()
+            .map(|v| v.map(to_datetime))
+            .collect_vec();
+
+        let df = DataFrame::new(height, vec![col, col_opt]).unwrap();
+
+        let expected_rows = izip!(col_values, col_opt_values)
+            .map(|(col, col_opt)| TestRow { col, col_opt })
+            .collect_vec();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            col: OffsetDateTime,
+            col_opt: Option<OffsetDateTime>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(rows, expected_rows)
+    }
+
+    #[test]
+    fn offset_datetime_should_respect_time_units() {
+        let ms = Column::new("ms".into(), vec![1732122821123i64])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let us = Column::new("us".into(), vec![1732122821123456i64])
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap();
+        let ns = Column::new("ns".into(), vec![1732122821123456789i64])
+            .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+            .unwrap();
+
+        let df = DataFrame::new(1, vec![ms, us, ns]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            ms: OffsetDateTime,
+            us: OffsetDateTime,
+            ns: OffsetDateTime,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![TestRow {
+                ms: datetime!(2024-11-20 17:13:41.123 UTC),
+                us: datetime!(2024-11-20 17:13:41.123456 UTC),
+                ns: datetime!(2024-11-20 17:13:41.123456789 UTC),
+            }]
+        );
+    }
+}
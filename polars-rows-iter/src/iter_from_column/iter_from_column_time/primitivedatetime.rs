@@ -0,0 +1,96 @@
+use super::create_utc_datetime;
+use crate::{iter_from_column::iter_from_column_temporal::create_naive_datetime_iter, *};
+use polars::prelude::*;
+use time::PrimitiveDateTime;
+
+impl<'a> IterFromColumn<'a> for PrimitiveDateTime {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        let timestamp = polars_value
+            .ok_or_else(|| <PrimitiveDateTime as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+
+        create_datetime(timestamp, column_name, dtype)
+    }
+}
+
+impl<'a> IterFromColumn<'a> for Option<PrimitiveDateTime> {
+    type RawInner = i64;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a>
+    where
+        Self: Sized,
+    {
+        create_datetime_iter(column)
+    }
+
+    fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|timestamp| create_datetime(timestamp, column_name, dtype))
+            .transpose()
+    }
+}
+
+fn create_datetime_iter<'a>(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<i64>> + 'a> {
+    create_naive_datetime_iter(column, "time::PrimitiveDateTime", "time::OffsetDateTime")
+}
+
+fn create_datetime(timestamp: i64, column_name: &str, dtype: &DataType) -> PolarsResult<PrimitiveDateTime> {
+    let datetime = create_utc_datetime(timestamp, column_name, dtype)?;
+    Ok(PrimitiveDateTime::new(datetime.date(), datetime.time()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn primitive_datetime_should_be_read_from_datetime_micros() {
+        let column = Column::new("dt".into(), vec![Some(1732122821123456i64), None, Some(-1)])
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let values = df
+            .scalar_iter("dt")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Option<PrimitiveDateTime>>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![
+                Some(datetime!(2024-11-20 17:13:41.123456)),
+                None,
+                Some(datetime!(1969-12-31 23:59:59.999999)),
+            ]
+        );
+    }
+
+    #[test]
+    fn primitive_datetime_should_error_on_out_of_range_values() {
+        let column = Column::new("dt".into(), vec![i64::MAX])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let df = DataFrame::new(column.len(), vec![column]).unwrap();
+
+        let result = df
+            .scalar_iter("dt")
+            .unwrap()
+            .collect::<PolarsResult<Vec<PrimitiveDateTime>>>();
+
+        assert!(result.is_err());
+    }
+}
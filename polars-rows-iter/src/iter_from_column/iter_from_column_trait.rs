@@ -15,3 +15,29 @@ pub trait IterFromColumn<'a> {
         polars_err!(SchemaMismatch: "Found unexpected None/null value in column '{column_name}' with mandatory values!")
     }
 }
+
+/// Marks types whose `Option<Self>` is read from the same column values as `Self`, with null values mapped to `None`.
+///
+/// Derive macros implement this marker instead of `IterFromColumn` for `Option<Self>`, which the orphan rule
+/// doesn't allow outside of this crate.
+pub trait NullableIterFromColumn {}
+
+impl<'a, T> IterFromColumn<'a> for Option<T>
+where
+    T: IterFromColumn<'a> + NullableIterFromColumn,
+{
+    type RawInner = T::RawInner;
+    fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+        T::create_iter(column)
+    }
+
+    #[inline]
+    fn get_value(polars_value: Option<Self::RawInner>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        polars_value
+            .map(|value| T::get_value(Some(value), column_name, dtype))
+            .transpose()
+    }
+}
@@ -42,7 +42,7 @@ where
     }
 }
 
-fn collect_inner_values<T>(series: Series, column_name: &str) -> PolarsResult<Vec<T>>
+pub(crate) fn collect_inner_values<T>(series: Series, column_name: &str) -> PolarsResult<Vec<T>>
 where
     T: for<'inner> IterFromColumn<'inner>,
 {
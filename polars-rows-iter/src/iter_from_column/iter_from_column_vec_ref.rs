@@ -0,0 +1,409 @@
+use crate::*;
+use polars::prelude::*;
+
+macro_rules! impl_iter_from_column_for_vec_ref {
+    ($ty:ty, $create_iter:ident) => {
+        impl<'a> IterFromColumn<'a> for Vec<&'a $ty> {
+            type RawInner = Vec<Option<&'a $ty>>;
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                $create_iter(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                column_name: &str,
+                _dtype: &DataType,
+            ) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                let values = polars_value
+                    .ok_or_else(|| <Self as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?;
+                unwrap_inner_values::<$ty>(values, column_name)
+            }
+        }
+
+        impl<'a> IterFromColumn<'a> for Option<Vec<&'a $ty>> {
+            type RawInner = Vec<Option<&'a $ty>>;
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                $create_iter(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                column_name: &str,
+                _dtype: &DataType,
+            ) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                polars_value
+                    .map(|values| unwrap_inner_values::<$ty>(values, column_name))
+                    .transpose()
+            }
+        }
+
+        impl<'a> IterFromColumn<'a> for Vec<Option<&'a $ty>> {
+            type RawInner = Vec<Option<&'a $ty>>;
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                $create_iter(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                column_name: &str,
+                _dtype: &DataType,
+            ) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                polars_value.ok_or_else(|| <Self as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
+            }
+        }
+
+        impl<'a> IterFromColumn<'a> for Option<Vec<Option<&'a $ty>>> {
+            type RawInner = Vec<Option<&'a $ty>>;
+            fn create_iter(column: &'a Column) -> PolarsResult<impl Iterator<Item = Option<Self::RawInner>> + 'a> {
+                $create_iter(column)
+            }
+
+            #[inline]
+            fn get_value(
+                polars_value: Option<Self::RawInner>,
+                _column_name: &str,
+                _dtype: &DataType,
+            ) -> PolarsResult<Self>
+            where
+                Self: Sized,
+            {
+                Ok(polars_value)
+            }
+        }
+    };
+}
+
+impl_iter_from_column_for_vec_ref!(str, create_str_list_iter);
+impl_iter_from_column_for_vec_ref!([u8], create_binary_list_iter);
+
+fn unwrap_inner_values<'a, T: ?Sized>(values: Vec<Option<&'a T>>, column_name: &str) -> PolarsResult<Vec<&'a T>> {
+    values
+        .into_iter()
+        .map(|v| v.ok_or_else(|| <&str as IterFromColumn>::unexpected_null_value_error(column_name)))
+        .collect()
+}
+
+type ValuesIter<'a, T> = Box<dyn Iterator<Item = Option<&'a T>> + 'a>;
+type ListsIter<'a, T> = Box<dyn Iterator<Item = Option<Vec<Option<&'a T>>>> + 'a>;
+
+fn create_str_list_iter<'a>(
+    column: &'a Column,
+) -> PolarsResult<impl Iterator<Item = Option<Vec<Option<&'a str>>>> + 'a> {
+    let column_name = column.name().as_str();
+    let iter: ListsIter<'a, str> = match get_inner_dtype(column)? {
+        DataType::String => create_list_iter(column, |values| {
+            let values = downcast_values::<StringType>(values)?;
+            Ok(Box::new(values.iter()) as ValuesIter<'a, str>)
+        })?,
+        #[cfg(feature = "dtype-categorical")]
+        inner_dtype @ (DataType::Categorical(_, mapping) | DataType::Enum(_, mapping)) => {
+            let mapping = mapping.as_ref();
+            match inner_dtype.cat_physical()? {
+                CategoricalPhysical::U8 => create_list_iter(column, move |values| {
+                    let values = downcast_values::<UInt8Type>(values)?;
+                    Ok(Box::new(
+                        values
+                            .iter()
+                            .map(move |cat| cat.and_then(|cat| mapping.cat_to_str(*cat as CatSize))),
+                    ) as ValuesIter<'a, str>)
+                })?,
+                CategoricalPhysical::U16 => create_list_iter(column, move |values| {
+                    let values = downcast_values::<UInt16Type>(values)?;
+                    Ok(Box::new(
+                        values
+                            .iter()
+                            .map(move |cat| cat.and_then(|cat| mapping.cat_to_str(*cat as CatSize))),
+                    ) as ValuesIter<'a, str>)
+                })?,
+                CategoricalPhysical::U32 => create_list_iter(column, move |values| {
+                    let values = downcast_values::<UInt32Type>(values)?;
+                    Ok(Box::new(
+                        values
+                            .iter()
+                            .map(move |cat| cat.and_then(|cat| mapping.cat_to_str(*cat))),
+                    ) as ValuesIter<'a, str>)
+                })?,
+            }
+        }
+        dtype => {
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get Vec<&str> from column '{column_name}' with inner dtype '{dtype}'.\
+                                             Make sure to enable 'dtype-categorical' feature for 'Categorical' and 'Enum' dtypes."),
+            )
+        }
+    };
+
+    Ok(iter)
+}
+
+fn create_binary_list_iter<'a>(
+    column: &'a Column,
+) -> PolarsResult<impl Iterator<Item = Option<Vec<Option<&'a [u8]>>>> + 'a> {
+    let column_name = column.name().as_str();
+    match get_inner_dtype(column)? {
+        DataType::Binary => create_list_iter(column, |values| {
+            let values = downcast_values::<BinaryType>(values)?;
+            Ok(Box::new(values.iter()) as ValuesIter<'a, [u8]>)
+        }),
+        dtype => Err(
+            polars_err!(SchemaMismatch: "Cannot get Vec<&[u8]> from column '{column_name}' with inner dtype: {dtype}"),
+        ),
+    }
+}
+
+fn get_inner_dtype(column: &Column) -> PolarsResult<&DataType> {
+    let column_name = column.name().as_str();
+    match column.dtype() {
+        DataType::List(inner_dtype) => Ok(inner_dtype.as_ref()),
+        dtype => Err(polars_err!(SchemaMismatch: "Cannot get Vec from column '{column_name}' with dtype: {dtype}")),
+    }
+}
+
+fn downcast_values<T: PolarsDataType>(values: &ArrayRef) -> PolarsResult<&T::Array> {
+    values
+        .as_any()
+        .downcast_ref::<T::Array>()
+        .ok_or_else(|| polars_err!(ComputeError: "Unexpected physical type of list values: {:?}", values.dtype()))
+}
+
+/// Borrows the inner values of every list straight from the arrow buffers of the list chunks,
+/// so the values live as long as the column itself.
+fn create_list_iter<'a, T, F>(column: &'a Column, create_values_iter: F) -> PolarsResult<ListsIter<'a, T>>
+where
+    T: ?Sized + 'a,
+    F: Fn(&'a ArrayRef) -> PolarsResult<ValuesIter<'a, T>>,
+{
+    let chunks = column
+        .list()?
+        .downcast_iter()
+        .map(|list_array| {
+            let offsets = list_array.offsets().as_slice();
+            let validity = list_array.validity();
+
+            let first_offset = offsets.first().copied().unwrap_or_default() as usize;
+            let mut values = create_values_iter(list_array.values())?.skip(first_offset);
+
+            let rows = offsets.windows(2).enumerate().map(move |(index, window)| {
+                let list_values = values
+                    .by_ref()
+                    .take((window[1] - window[0]) as usize)
+                    .collect::<Vec<_>>();
+                let is_valid = validity.is_none_or(|validity| validity.get_bit(index));
+                is_valid.then_some(list_values)
+            });
+
+            Ok(rows)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(Box::new(chunks.into_iter().flatten()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use itertools::Itertools;
+    use polars::prelude::*;
+
+    #[test]
+    fn vec_str_rows_iter_test() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &["a", "b", "c"]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &["d", "e"]);
+        let s3 = Series::new(PlSmallStr::EMPTY, &["f"]);
+
+        let col = Column::new("col".into(), vec![s1.clone(), s2.clone(), s3.clone()]);
+        let col_opt = Column::new("col_opt".into(), vec![Some(s1), None, Some(s3)]);
+
+        let df = DataFrame::new(3, vec![col, col_opt]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow<'a> {
+            col: Vec<&'a str>,
+            col_opt: Option<Vec<&'a str>>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    col: vec!["a", "b", "c"],
+                    col_opt: Some(vec!["a", "b", "c"])
+                },
+                TestRow {
+                    col: vec!["d", "e"],
+                    col_opt: None
+                },
+                TestRow {
+                    col: vec!["f"],
+                    col_opt: Some(vec!["f"])
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn vec_option_str_scalar_iter_test() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &[Some("a"), None]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &[Some("c")]);
+
+        let col = Column::new("col".into(), vec![Some(s1), None, Some(s2)]);
+        let df = DataFrame::new(3, vec![col]).unwrap();
+
+        let values = df
+            .scalar_iter("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Option<Vec<Option<&str>>>>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![Some(vec![Some("a"), None]), None, Some(vec![Some("c")])]);
+    }
+
+    #[test]
+    fn vec_str_borrows_from_multiple_sliced_chunks() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &["a", "b"]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &["c"]);
+        let s3 = Series::new(PlSmallStr::EMPTY, &["d", "e", "f"]);
+
+        let mut col = Column::new("col".into(), vec![s1.clone(), s2.clone()]);
+        col.append(&Column::new("col".into(), vec![s3, s1, s2])).unwrap();
+        let col = col.slice(1, 3);
+
+        let df = DataFrame::new(3, vec![col]).unwrap();
+
+        let values = df
+            .scalar_iter("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Vec<&str>>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![vec!["c"], vec!["d", "e", "f"], vec!["a", "b"]]);
+    }
+
+    #[test]
+    fn vec_str_errors_on_null_inner_values() {
+        let column_name = "col";
+
+        let s1 = Series::new(PlSmallStr::EMPTY, &[Some("a"), None]);
+
+        let col = Column::new(column_name.into(), vec![s1]);
+        let df = DataFrame::new(1, vec![col]).unwrap();
+
+        let err_msg = df
+            .scalar_iter(column_name)
+            .unwrap()
+            .collect::<PolarsResult<Vec<Vec<&str>>>>()
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            err_msg,
+            format!("Found unexpected None/null value in column '{column_name}' with mandatory values!"),
+        );
+    }
+
+    #[test]
+    fn vec_str_errors_on_non_string_list_column() {
+        let column_name = "col";
+
+        let s1 = Series::new(PlSmallStr::EMPTY, &[1i32, 2]);
+
+        let col = Column::new(column_name.into(), vec![s1]);
+        let df = DataFrame::new(1, vec![col]).unwrap();
+
+        assert!(df.scalar_iter::<Vec<&str>>(column_name).is_err());
+    }
+
+    #[test]
+    fn vec_binary_rows_iter_test() {
+        let s1 = Series::new(PlSmallStr::EMPTY, &[b"a".as_slice(), b"bc"]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &[b"d".as_slice()]);
+
+        let col = Column::new("col".into(), vec![s1.clone(), s2]);
+        let col_opt = Column::new("col_opt".into(), vec![None, Some(s1)]);
+
+        let df = DataFrame::new(2, vec![col, col_opt]).unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow<'a> {
+            col: Vec<&'a [u8]>,
+            col_opt: Option<Vec<&'a [u8]>>,
+        }
+
+        let rows = df.rows_iter::<TestRow>().unwrap().map(|v| v.unwrap()).collect_vec();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    col: vec![b"a".as_slice(), b"bc"],
+                    col_opt: None
+                },
+                TestRow {
+                    col: vec![b"d".as_slice()],
+                    col_opt: Some(vec![b"a".as_slice(), b"bc"])
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "dtype-categorical")]
+    #[test]
+    fn vec_str_from_categorical_list_test() {
+        let cats = Categories::new(PlSmallStr::EMPTY, PlSmallStr::EMPTY, CategoricalPhysical::U32);
+        let dtype = DataType::List(Box::new(DataType::from_categories(cats)));
+
+        let s1 = Series::new(PlSmallStr::EMPTY, &[Some("x"), None, Some("y")]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &["y"]);
+
+        let col = Column::new("col".into(), vec![Some(s1), None, Some(s2)])
+            .cast(&dtype)
+            .unwrap();
+        let df = DataFrame::new(3, vec![col]).unwrap();
+
+        let values = df
+            .scalar_iter("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Option<Vec<Option<&str>>>>>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![Some(vec![Some("x"), None, Some("y")]), None, Some(vec![Some("y")])]
+        );
+    }
+
+    #[cfg(feature = "dtype-categorical")]
+    #[test]
+    fn vec_str_from_enum_list_test() {
+        let categories = FrozenCategories::new(["A", "B", "C"]).unwrap();
+        let dtype = DataType::List(Box::new(DataType::from_frozen_categories(categories)));
+
+        let s1 = Series::new(PlSmallStr::EMPTY, &["C", "A"]);
+        let s2 = Series::new(PlSmallStr::EMPTY, &["B"]);
+
+        let col = Column::new("col".into(), vec![s1, s2]).cast(&dtype).unwrap();
+        let df = DataFrame::new(2, vec![col]).unwrap();
+
+        let values = df
+            .scalar_iter("col")
+            .unwrap()
+            .collect::<PolarsResult<Vec<Vec<&str>>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![vec!["C", "A"], vec!["B"]]);
+    }
+}
@@ -0,0 +1,238 @@
+use polars::prelude::*;
+
+/// Primitive types that can be read from columns of narrower numeric types.
+/// The accepted dtypes follow the lossless `From` conversions of the standard library.
+pub(crate) trait WidenFromColumn: Sized {
+    /// The iterator of `create_primitive_iter`, for types without narrower types the one of `create_iter`.
+    type Iter<'a, I>: Iterator<Item = Option<Self>> + 'a
+    where
+        Self: 'a,
+        I: Iterator<Item = Option<Self>> + 'a;
+
+    fn create_primitive_iter<'a, I>(
+        column: &'a Column,
+        create_iter: impl FnOnce(&'a Column) -> PolarsResult<I>,
+    ) -> PolarsResult<Self::Iter<'a, I>>
+    where
+        I: Iterator<Item = Option<Self>> + 'a;
+}
+
+/// Creates the iterator of a primitive type, which is either the widened column or the one of `create_iter`.
+pub(crate) fn create_primitive_iter<'a, T, I>(
+    column: &'a Column,
+    create_iter: impl FnOnce(&'a Column) -> PolarsResult<I>,
+) -> PolarsResult<T::Iter<'a, I>>
+where
+    T: WidenFromColumn + 'a,
+    I: Iterator<Item = Option<T>> + 'a,
+{
+    T::create_primitive_iter(column, create_iter)
+}
+
+/// Iterator of a primitive type that can be widened, only the widened columns are boxed
+/// since their iterator type depends on the dtype.
+pub(crate) enum PrimitiveIter<'a, T, I> {
+    Native(I),
+    Widened(Box<dyn Iterator<Item = Option<T>> + 'a>),
+}
+
+impl<T, I> Iterator for PrimitiveIter<'_, T, I>
+where
+    I: Iterator<Item = Option<T>>,
+{
+    type Item = Option<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            PrimitiveIter::Native(iter) => iter.next(),
+            PrimitiveIter::Widened(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            PrimitiveIter::Native(iter) => iter.size_hint(),
+            PrimitiveIter::Widened(iter) => iter.size_hint(),
+        }
+    }
+}
+
+fn widen<'a, P, T, I>(column: &'a Column) -> PolarsResult<PrimitiveIter<'a, T, I>>
+where
+    P: PolarsNumericType,
+    T: From<P::Native> + 'a,
+{
+    let iter = column.as_materialized_series().unpack::<P>()?.iter();
+    Ok(PrimitiveIter::Widened(Box::new(iter.map(|value| value.map(T::from)))))
+}
+
+macro_rules! impl_widen_from_column {
+    ($ty:ty) => {
+        impl WidenFromColumn for $ty {
+            type Iter<'a, I>
+                = I
+            where
+                I: Iterator<Item = Option<Self>> + 'a;
+
+            fn create_primitive_iter<'a, I>(
+                column: &'a Column,
+                create_iter: impl FnOnce(&'a Column) -> PolarsResult<I>,
+            ) -> PolarsResult<I>
+            where
+                I: Iterator<Item = Option<Self>> + 'a,
+            {
+                create_iter(column)
+            }
+        }
+    };
+    ($ty:ty $(, $dtype:ident => $polars_type:ty)+) => {
+        impl WidenFromColumn for $ty {
+            type Iter<'a, I>
+                = PrimitiveIter<'a, Self, I>
+            where
+                I: Iterator<Item = Option<Self>> + 'a;
+
+            fn create_primitive_iter<'a, I>(
+                column: &'a Column,
+                create_iter: impl FnOnce(&'a Column) -> PolarsResult<I>,
+            ) -> PolarsResult<PrimitiveIter<'a, Self, I>>
+            where
+                I: Iterator<Item = Option<Self>> + 'a,
+            {
+                match column.dtype() {
+                    $(DataType::$dtype => widen::<$polars_type, $ty, I>(column),)+
+                    _ => Ok(PrimitiveIter::Native(create_iter(column)?)),
+                }
+            }
+        }
+    };
+}
+
+impl_widen_from_column!(bool);
+impl_widen_from_column!(i8);
+impl_widen_from_column!(i16, Int8 => Int8Type, UInt8 => UInt8Type);
+impl_widen_from_column!(i32, Int8 => Int8Type, Int16 => Int16Type, UInt8 => UInt8Type, UInt16 => UInt16Type);
+impl_widen_from_column!(
+    i64,
+    Int8 => Int8Type,
+    Int16 => Int16Type,
+    Int32 => Int32Type,
+    UInt8 => UInt8Type,
+    UInt16 => UInt16Type,
+    UInt32 => UInt32Type
+);
+#[cfg(any(feature = "dtype-decimal", feature = "dtype-i128"))]
+impl_widen_from_column!(
+    i128,
+    Int8 => Int8Type,
+    Int16 => Int16Type,
+    Int32 => Int32Type,
+    Int64 => Int64Type,
+    UInt8 => UInt8Type,
+    UInt16 => UInt16Type,
+    UInt32 => UInt32Type,
+    UInt64 => UInt64Type
+);
+impl_widen_from_column!(u8);
+impl_widen_from_column!(u16, UInt8 => UInt8Type);
+impl_widen_from_column!(u32, UInt8 => UInt8Type, UInt16 => UInt16Type);
+impl_widen_from_column!(u64, UInt8 => UInt8Type, UInt16 => UInt16Type, UInt32 => UInt32Type);
+#[cfg(feature = "dtype-u128")]
+impl_widen_from_column!(
+    u128,
+    UInt8 => UInt8Type,
+    UInt16 => UInt16Type,
+    UInt32 => UInt32Type,
+    UInt64 => UInt64Type
+);
+impl_widen_from_column!(f32, Int8 => Int8Type, Int16 => Int16Type, UInt8 => UInt8Type, UInt16 => UInt16Type);
+impl_widen_from_column!(
+    f64,
+    Float32 => Float32Type,
+    Int8 => Int8Type,
+    Int16 => Int16Type,
+    Int32 => Int32Type,
+    UInt8 => UInt8Type,
+    UInt16 => UInt16Type,
+    UInt32 => UInt32Type
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use polars::prelude::*;
+
+    #[test]
+    fn integers_are_widened() {
+        let df = df!(
+            "small" => [Some(-3i8), None, Some(127)],
+            "unsigned" => [Some(65535u16), Some(0), None],
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow, PartialEq)]
+        struct TestRow {
+            #[column("small")]
+            small_i64: Option<i64>,
+            #[column("small")]
+            small_f64: Option<f64>,
+            #[column("unsigned")]
+            unsigned_u32: Option<u32>,
+            #[column("unsigned")]
+            unsigned_i32: Option<i32>,
+        }
+
+        let rows = df
+            .rows_iter::<TestRow>()
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                TestRow {
+                    small_i64: Some(-3),
+                    small_f64: Some(-3.0),
+                    unsigned_u32: Some(65535),
+                    unsigned_i32: Some(65535)
+                },
+                TestRow {
+                    small_i64: None,
+                    small_f64: None,
+                    unsigned_u32: Some(0),
+                    unsigned_i32: Some(0)
+                },
+                TestRow {
+                    small_i64: Some(127),
+                    small_f64: Some(127.0),
+                    unsigned_u32: None,
+                    unsigned_i32: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn floats_are_widened() {
+        let df = df!("value" => [1.5f32, -0.25]).unwrap();
+
+        let values = df
+            .scalar_iter::<f64>("value")
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(values, vec![1.5, -0.25]);
+    }
+
+    #[test]
+    fn lossy_conversions_are_not_widened() {
+        let df = df!("value" => [1i64], "signed" => [1i32]).unwrap();
+
+        assert!(df.scalar_iter::<i32>("value").is_err());
+        assert!(df.scalar_iter::<f32>("value").is_err());
+        assert!(df.scalar_iter::<u64>("signed").is_err());
+    }
+}
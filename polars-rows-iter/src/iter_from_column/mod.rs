@@ -1,13 +1,32 @@
+mod iter_from_column_array;
 mod iter_from_column_binary;
+mod iter_from_column_cast;
 #[cfg(feature = "chrono")]
 mod iter_from_column_chrono;
+mod iter_from_column_cow;
+mod iter_from_column_duration;
+#[cfg(any(feature = "dtype-decimal", feature = "dtype-i128"))]
+mod iter_from_column_i128;
 mod iter_from_column_i32;
 mod iter_from_column_i64;
+#[cfg(feature = "jiff")]
+mod iter_from_column_jiff;
 mod iter_from_column_primitives;
+#[cfg(feature = "rust_decimal")]
+mod iter_from_column_rust_decimal;
 mod iter_from_column_series;
+mod iter_from_column_slice;
 mod iter_from_column_str;
 mod iter_from_column_string;
+mod iter_from_column_struct;
+mod iter_from_column_temporal;
+#[cfg(feature = "time")]
+mod iter_from_column_time;
 mod iter_from_column_trait;
 mod iter_from_column_vec;
+mod iter_from_column_vec_ref;
+mod iter_from_column_widening;
 
-pub use iter_from_column_trait::IterFromColumn;
+pub use iter_from_column_cast::Cast;
+pub use iter_from_column_trait::{IterFromColumn, NullableIterFromColumn};
+use iter_from_column_widening::create_primitive_iter;
@@ -123,30 +123,457 @@
 //!   `UpperFlat`, `Alternating`
 //! - `prefix("str")` - Add a prefix to all column names
 //! - `postfix("str")` - Add a postfix/suffix to all column names
+//! - `tag = "column"` - Select the variant of an enum by the value of this column, see [Tagged enums](#tagged-enums)
+//! - `cast = "lossy"` - Cast all numeric fields, see [Numeric casts](#numeric-casts)
+//! - `row_index_offset = n` - Add `n` to the row positions of `#[row_index]` fields, see [Row index](#row-index)
 //!
 //! These can be combined: `#[from_dataframe(convert_case(Snake), prefix("data_"), postfix("_col"))]`
 //!
 //! Individual fields can still override with `#[column("explicit_name")]`.
 //!
+//! ## Parsing string columns
+//!
+//! With `#[column(parse)]` a field is read from a string column and converted with `FromStr`.
+//! With the `chrono` feature, `#[column(parse_datetime = "format")]` parses chrono date and time types with the
+//! given format. A value that can't be parsed fails its row with an error naming the column and the value.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//! use std::net::Ipv4Addr;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Request {
+//!     #[column("client", parse)]
+//!     client_ip: Ipv4Addr,
+//!     #[column(parse)]
+//!     status: Option<u16>,
+//! }
+//!
+//! let df = df!("client" => ["10.0.0.1"], "status" => ["200"]).unwrap();
+//!
+//! let request = df.rows_iter::<Request>().unwrap().next().unwrap().unwrap();
+//!
+//! assert_eq!(request.client_ip, Ipv4Addr::new(10, 0, 0, 1));
+//! assert_eq!(request.status, Some(200));
+//! ```
+//!
+//! ## Custom conversions
+//!
+//! `#[column(with = path::to::function, from = Type)]` reads the column as `Type`, with any type supported by
+//! `IterFromColumn` including `Option<...>`, and converts the value with a function returning
+//! `PolarsResult<FieldType>`. An error returned by the function fails the row.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! fn to_cents(value: f64) -> PolarsResult<i64> {
+//!     Ok((value * 100.0).round() as i64)
+//! }
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Item {
+//!     #[column("price", with = to_cents, from = f64)]
+//!     price_cents: i64,
+//! }
+//!
+//! let df = df!("price" => [1.99]).unwrap();
+//!
+//! let item = df.rows_iter::<Item>().unwrap().next().unwrap().unwrap();
+//!
+//! assert_eq!(item.price_cents, 199);
+//! ```
+//!
+//! ## Numeric casts
+//!
+//! Numeric fields are read from columns of narrower numeric types without any attribute, as long as the
+//! conversion is lossless, like `Int32` into `i64` or `Float32` into `f64` (see [Supported types](#supported-types)).
+//! Other numeric conversions have to be requested with `#[column(cast)]`, or with `#[from_dataframe(cast = "lossy")]`
+//! for all numeric fields of a struct. The column is then cast to the field type once before iterating, following the
+//! Polars cast rules, and values that are out of range for the field type fail their row.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Reading {
+//!     sensor: i64,
+//!     #[column(cast)]
+//!     level: u8,
+//! }
+//!
+//! let df = df!("sensor" => [7i32, 8], "level" => [200i64, 300]).unwrap();
+//!
+//! let mut readings = df.rows_iter::<Reading>().unwrap();
+//!
+//! assert_eq!(readings.next().unwrap().unwrap().level, 200);
+//! assert!(readings.next().unwrap().is_err());
+//! ```
+//!
+//! ## Defaults for null values
+//!
+//! A null value fails its row unless the field is an `Option<...>`. With `#[column(default)]` a field gets
+//! `Default::default()` for nulls instead, and with `#[column(default = expr)]` the value of the expression,
+//! which is only evaluated for nulls. This also works together with `parse`, `with` and `cast`, except for a `with`
+//! function reading `Option<...>`, which gets the nulls itself.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Job<'a> {
+//!     #[column(default)]
+//!     retries: u32,
+//!     #[column(default = "default")]
+//!     queue: &'a str,
+//! }
+//!
+//! let df = df!("retries" => [None::<u32>], "queue" => [None::<&str>]).unwrap();
+//!
+//! let job = df.rows_iter::<Job>().unwrap().next().unwrap().unwrap();
+//!
+//! assert_eq!(job.retries, 0);
+//! assert_eq!(job.queue, "default");
+//! ```
+//!
+//! ## Missing columns
+//!
+//! A column that doesn't exist in the DataFrame fails `rows_iter`, unless the field has
+//! `#[column(missing = "none")]`, which requires an `Option<...>` field, or `#[column(missing = default)]`,
+//! which uses the `default` of the field or `Default::default()`. The value is then the same for every row.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Order<'a> {
+//!     id: i64,
+//!     #[column(missing = "none")]
+//!     channel: Option<&'a str>,
+//!     #[column(missing = default, default = 1)]
+//!     quantity: u32,
+//! }
+//!
+//! let df = df!("id" => [1i64]).unwrap();
+//!
+//! let order = df.rows_iter::<Order>().unwrap().next().unwrap().unwrap();
+//!
+//! assert_eq!(order.channel, None);
+//! assert_eq!(order.quantity, 1);
+//! ```
+//!
+//! ## Skipped fields
+//!
+//! Fields with `#[column(skip)]` aren't read from any column and are set to `Default::default()`, or to the
+//! value of the expression with `#[column(skip, default = expr)]`. This allows computed or cached fields in
+//! row structs.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Page {
+//!     url: String,
+//!     #[column(skip)]
+//!     links: Vec<String>,
+//!     #[column(skip, default = 1)]
+//!     depth: u32,
+//! }
+//!
+//! let df = df!("url" => ["https://example.com"]).unwrap();
+//!
+//! let page = df.rows_iter::<Page>().unwrap().next().unwrap().unwrap();
+//!
+//! assert!(page.links.is_empty());
+//! assert_eq!(page.depth, 1);
+//! ```
+//!
+//! ## Flattened structs
+//!
+//! A field with `#[column(flatten)]` is another struct deriving `FromDataFrameRow`, which is read from the
+//! columns of the same DataFrame. With `#[column(flatten, prefix = "billing_")]` its column names get the prefix,
+//! and the `#[from_dataframe(...)]` transformations of the outer struct are applied on top. The columns of a
+//! flattened struct can't be remapped at runtime: `rows_iter_with_columns` only renames the columns of the fields of
+//! the outer struct, and `get_column_names` only lists those. Structs with `#[columns(...)]` fields can't be
+//! flattened, since their regex is matched against the column names as they are, and neither can structs with a
+//! `#[column(rest)]` field, since it can't exclude the columns read by the outer struct.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Address<'a> {
+//!     street: &'a str,
+//!     city: &'a str,
+//! }
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Customer<'a> {
+//!     name: &'a str,
+//!     #[column(flatten, prefix = "billing_")]
+//!     billing: Address<'a>,
+//!     #[column(flatten, prefix = "shipping_")]
+//!     shipping: Address<'a>,
+//! }
+//!
+//! let df = df!(
+//!     "name" => ["Ada"],
+//!     "billing_street" => ["Main St 1"],
+//!     "billing_city" => ["London"],
+//!     "shipping_street" => ["Dock 4"],
+//!     "shipping_city" => ["Leeds"],
+//! )
+//! .unwrap();
+//!
+//! let customer = df.rows_iter::<Customer>().unwrap().next().unwrap().unwrap();
+//!
+//! assert_eq!(customer.billing.city, "London");
+//! assert_eq!(customer.shipping.street, "Dock 4");
+//! ```
+//!
+//! ## Matching columns
+//!
+//! With the `regex` feature, a field with `#[columns(regex = "...")]` is read from every column whose name matches
+//! the regex. A `Vec<T>` field gets the values in column order, or with `order = "natural"` sorted by name with
+//! numbers compared by value (`feat_2` before `feat_10`). A `HashMap<String, T>` or `BTreeMap<String, T>` field is
+//! keyed by column name. The matching columns are resolved once when the iterator is created, and the regex is
+//! matched against the column names of the DataFrame as they are. A regex given as a string literal is already
+//! checked at compile time.
+//!
+//! ```rust
+//! # #[cfg(feature = "regex")]
+//! # fn main() {
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//! use std::collections::BTreeMap;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Sample {
+//!     id: i64,
+//!     #[columns(regex = "^feat_\\d+$", order = "natural")]
+//!     features: Vec<f32>,
+//!     #[columns(regex = "^sensor_")]
+//!     sensors: BTreeMap<String, Option<f64>>,
+//! }
+//!
+//! let df = df!(
+//!     "id" => [1i64],
+//!     "feat_10" => [0.5f32],
+//!     "feat_2" => [0.25f32],
+//!     "sensor_a" => [Some(1.5f64)],
+//!     "sensor_b" => [None::<f64>],
+//! )
+//! .unwrap();
+//!
+//! let sample = df.rows_iter::<Sample>().unwrap().next().unwrap().unwrap();
+//!
+//! assert_eq!(sample.features, vec![0.25, 0.5]);
+//! assert_eq!(sample.sensors["sensor_a"], Some(1.5));
+//! assert_eq!(sample.sensors["sensor_b"], None);
+//! # }
+//! # #[cfg(not(feature = "regex"))]
+//! # fn main() {}
+//! ```
+//!
+//! ## Remaining columns
+//!
+//! A field with `#[column(rest)]` gets the values of all columns that aren't read by any other field, including
+//! the columns of flattened structs, matching columns and the tag column. Its type is a collection of
+//! `(column name, AnyValue<'a>)` pairs, like `HashMap<String, AnyValue<'a>>` or `Vec<(&'a str, AnyValue<'a>)>`,
+//! with the `Vec` keeping the column order.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//! use std::collections::HashMap;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Event<'a> {
+//!     id: i64,
+//!     #[column(rest)]
+//!     payload: HashMap<String, AnyValue<'a>>,
+//! }
+//!
+//! let df = df!("id" => [1i64], "source" => ["api"], "size" => [512u32]).unwrap();
+//!
+//! let event = df.rows_iter::<Event>().unwrap().next().unwrap().unwrap();
+//!
+//! assert_eq!(event.payload["source"], AnyValue::String("api"));
+//! assert_eq!(event.payload["size"], AnyValue::UInt32(512));
+//! ```
+//!
+//! ## Row index
+//!
+//! A field with `#[row_index]` is set to the position of the row in the DataFrame, as `usize`, `u32`, `u64`,
+//! `IdxSize` or any other type implementing `TryFrom<usize>`. `#[from_dataframe(row_index_offset = n)]` adds `n`
+//! to every position, e.g. to report 1-based line numbers or positions in the DataFrame a slice was taken from.
+//! Every row counts, so the positions stay correct when rows fail or are skipped with iterator adapters.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! #[from_dataframe(row_index_offset = 1)]
+//! struct Line<'a> {
+//!     #[row_index]
+//!     number: u32,
+//!     text: &'a str,
+//! }
+//!
+//! let df = df!("text" => ["first", "second", "third"]).unwrap();
+//!
+//! let line = df.rows_iter::<Line>().unwrap().nth(2).unwrap().unwrap();
+//!
+//! assert_eq!(line.number, 3);
+//! assert_eq!(line.text, "third");
+//! ```
+//!
+//! ## Nested structs
+//!
+//! With the `dtype-struct` feature, a field can be of another row struct type deriving `FromDataFrameRow`
+//! (or `Option<...>` of it), if the column has the `Struct(..)` dtype. The struct fields are mapped to the
+//! inner struct fields by name, following the inner struct's `#[column(...)]` and `#[from_dataframe(...)]`
+//! attributes. Tuples like `(f64, f64)` are read from the struct fields by position.
+//! Nested row structs and tuple elements have to own their values, so they can't have a lifetime.
+//!
+//! ## Newtypes
+//!
+//! Deriving `IterFromColumn` on a struct with a single field reads it like the type of the field,
+//! so domain types like `struct UserId(i64)` or `struct Email<'a>(&'a str)` can be used as field types
+//! (or `Option<...>` of them) without writing the impls by hand.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, PartialEq, IterFromColumn)]
+//! struct UserId(i64);
+//!
+//! #[derive(Debug, PartialEq, IterFromColumn)]
+//! struct Email<'a>(&'a str);
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct User<'a> {
+//!     id: UserId,
+//!     email: Option<Email<'a>>,
+//! }
+//!
+//! let df = df!("id" => [1i64], "email" => ["a@example.com"]).unwrap();
+//!
+//! let user = df.rows_iter::<User>().unwrap().next().unwrap().unwrap();
+//!
+//! assert_eq!(user.id, UserId(1));
+//! assert_eq!(user.email, Some(Email("a@example.com")));
+//! ```
+//!
+//! ## Tagged enums
+//!
+//! `FromDataFrameRow` can be derived for enums with a `#[from_dataframe(tag = "column")]` attribute.
+//! The variant of each row is selected by the string value of the tag column, which has to match the variant name
+//! or `#[from_dataframe(rename = "...")]` on the variant. Only the columns of the selected variant are read,
+//! so the columns of the other variants may contain nulls in that row.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, PartialEq, FromDataFrameRow)]
+//! #[from_dataframe(tag = "kind")]
+//! enum Event {
+//!     Click { x: i32, y: i32 },
+//!     #[from_dataframe(rename = "purchase")]
+//!     Purchase { amount: f64 },
+//! }
+//!
+//! let df = df!(
+//!     "kind" => ["Click", "purchase"],
+//!     "x" => [Some(1), None],
+//!     "y" => [Some(2), None],
+//!     "amount" => [None, Some(9.99)],
+//! )
+//! .unwrap();
+//!
+//! let events = df.rows_iter::<Event>().unwrap().collect::<PolarsResult<Vec<_>>>().unwrap();
+//!
+//! assert_eq!(events, vec![Event::Click { x: 1, y: 2 }, Event::Purchase { amount: 9.99 }]);
+//! ```
+//!
+//! ## Fieldless enums
+//!
+//! Deriving `FromColumnValue` on an enum with unit variants makes it usable as field type (or `Option<...>` of it).
+//! `String`, `Categorical` and `Enum` columns are matched against the variant names, which can be changed with
+//! `#[from_column_value(convert_case(...))]` on the enum and `#[from_column_value(rename("..."))]` (or
+//! `rename = "..."`) on a variant. The resulting names have to be unique.
+//! The categories of `Enum` columns are checked against the variants once, when the iterator is created.
+//! Integer columns are matched against the discriminants, if the enum declares explicit ones.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use polars_rows_iter::*;
+//!
+//! #[derive(Debug, PartialEq, FromColumnValue)]
+//! #[from_column_value(convert_case(Snake))]
+//! enum OrderStatus {
+//!     Pending,
+//!     InTransit,
+//!     #[from_column_value(rename("done"))]
+//!     Delivered,
+//! }
+//!
+//! #[derive(Debug, PartialEq, FromColumnValue)]
+//! enum Priority {
+//!     Low = 1,
+//!     High = 10,
+//! }
+//!
+//! #[derive(Debug, FromDataFrameRow)]
+//! struct Order {
+//!     status: OrderStatus,
+//!     priority: Option<Priority>,
+//! }
+//!
+//! let df = df!(
+//!     "status" => ["in_transit", "done"],
+//!     "priority" => [Some(10), None],
+//! )
+//! .unwrap();
+//!
+//! let orders = df.rows_iter::<Order>().unwrap().collect::<PolarsResult<Vec<_>>>().unwrap();
+//!
+//! assert_eq!(orders[0].status, OrderStatus::InTransit);
+//! assert_eq!(orders[0].priority, Some(Priority::High));
+//! assert_eq!(orders[1].status, OrderStatus::Delivered);
+//! assert_eq!(orders[1].priority, None);
+//! ```
+//!
 //! ## Supported types
 //!
 //! |State|Rust Type|Supported Polars DataType|Feature Flag|
 //! |--|--|--|--|
 //! |✓|`bool`|`Boolean`
 //! |✓|`u8`|`UInt8`
-//! |✓|`u16`|`UInt16`
-//! |✓|`u32`|`UInt32`
-//! |✓|`u64`|`UInt64`
+//! |✓|`u16`|`UInt16`, `UInt8`
+//! |✓|`u32`|`UInt32`, `UInt8`, `UInt16`
+//! |✓|`u64`|`UInt64`, `UInt8`..`UInt32`
+//! |✓|`u128`|`UInt128`, `UInt8`..`UInt64`|`dtype-u128`
 //! |✓|`i8`|`Int8`
-//! |✓|`i16`|`Int16`
-//! |✓|`i32`|`Int32`
+//! |✓|`i16`|`Int16`, `Int8`, `UInt8`
+//! |✓|`i32`|`Int32`, `Int8`, `Int16`, `UInt8`, `UInt16`
 //! |✓|`i32`|`Date`
-//! |✓|`i64`|`Int64`
+//! |✓|`i64`|`Int64`, `Int8`..`Int32`, `UInt8`..`UInt32`
 //! |✓|`i64`|`Datetime(..)`
 //! |✓|`i64`|`Duration(..)`
 //! |✓|`i64`|`Time`
-//! |✓|`f32`|`Float32`
-//! |✓|`f64`|`Float64`
+//! |✓|`i128`|`Int128`, `Int8`..`Int64`, `UInt8`..`UInt64`|`dtype-i128`
+//! |✓|`f32`|`Float32`, `Int8`, `Int16`, `UInt8`, `UInt16`
+//! |✓|`f64`|`Float64`, `Float32`, `Int8`..`Int32`, `UInt8`..`UInt32`
 //! |✓|`&str`|`String`
 //! |✓|`&str`|`Categorical(..)`|`dtype-categorical`
 //! |✓|`&str`|`Enum(..)`|`dtype-categorical`
@@ -155,16 +582,51 @@
 //! |✓|`String`|`Enum(..)`|`dtype-categorical`
 //! |✓|`&[u8]`|`Binary`
 //! |✓|`&[u8]`|`BinaryOffset`
-//! |✓|`chrono::NaiveDateTime`|`Datetime(..)`|`chrono`
-//! |✓|`chrono::DateTime<Utc>`|`Datetime(..)`|`chrono`
-//! |✓|`chrono::Date`|`Date`|`chrono`|
+//! |✓|`Cow<str>`|`String`
+//! |✓|`Cow<str>`|`Categorical(..)`|`dtype-categorical`
+//! |✓|`Cow<str>`|`Enum(..)`|`dtype-categorical`
+//! |✓|`Cow<str>`|`Binary`, `BinaryOffset`, owned if not valid UTF-8
+//! |✓|`Cow<[u8]>`|`Binary`
+//! |✓|`Cow<[u8]>`|`BinaryOffset`
+//! |✓|`Cow<[u8]>`|`List(UInt8)`
+//! |✓|`std::time::Duration`|`Duration(..)`, non-negative values
+//! |✓|`chrono::NaiveDateTime`|`Datetime(..)` without time zone or UTC|`chrono`
+//! |✓|`chrono::DateTime<Utc>`|`Datetime(..)`, zoned values are converted to UTC|`chrono`
+//! |✓|`chrono::DateTime<FixedOffset>`|`Datetime(..)` with time zone|`chrono`, `chrono-tz` for named time zones
+//! |✓|`chrono::DateTime<chrono_tz::Tz>`|`Datetime(..)` with time zone|`chrono-tz`
+//! |✓|`chrono::NaiveDate`|`Date`|`chrono`
+//! |✓|`chrono::NaiveTime`|`Time`|`chrono`
+//! |✓|`chrono::TimeDelta`|`Duration(..)`|`chrono`
+//! |✓|`time::OffsetDateTime`|`Datetime(..)`, in UTC|`time`
+//! |✓|`time::PrimitiveDateTime`|`Datetime(..)` without time zone or UTC|`time`
+//! |✓|`time::Date`|`Date`|`time`
+//! |✓|`time::Time`|`Time`|`time`
+//! |✓|`time::Duration`|`Duration(..)`|`time`
+//! |✓|`jiff::Timestamp`|`Datetime(..)`|`jiff`
+//! |✓|`jiff::Zoned`|`Datetime(..)` with time zone|`jiff`
+//! |✓|`jiff::civil::DateTime`|`Datetime(..)` without time zone or UTC|`jiff`
+//! |✓|`jiff::civil::Date`|`Date`|`jiff`
+//! |✓|`jiff::civil::Time`|`Time`|`jiff`
+//! |✓|`jiff::SignedDuration`|`Duration(..)`|`jiff`
 //! |✓|`polars::prelude::Series`|`List(..)`
 //! |✓|`Vec<T>`|`List(..)`
-//! |X|`Vec<&str>`|`List(..)`
-//! |X|`Vec<&[u8]>`|`List(..)`
-//! |?|?|`Array(..)`|
-//! |?|?|`Decimal(..)`|
-//! |?|?|`Struct(..)`|
+//! |✓|`Vec<&str>`|`List(String)`
+//! |✓|`Vec<&str>`|`List(Categorical(..))`|`dtype-categorical`
+//! |✓|`Vec<&str>`|`List(Enum(..))`|`dtype-categorical`
+//! |✓|`Vec<&[u8]>`|`List(Binary)`
+//! |✓|`&[T]` (numeric `T`, no inner nulls)|`List(..)`
+//! |✓|`polars::prelude::Series`|`Array(..)`|`dtype-array`
+//! |✓|`Vec<T>`|`Array(..)`|`dtype-array`
+//! |✓|`[T; N]`|`Array(..)`|`dtype-array`
+//! |✓|`i128`|`Decimal(..)`|`dtype-decimal`
+//! |✓|`rust_decimal::Decimal`|`Decimal(..)`|`rust_decimal`
+//! |✓|`T: FromDataFrameRow`|`Struct(..)`|`dtype-struct`
+//! |✓|`(T1, T2, ...)`|`Struct(..)`|`dtype-struct`
+//! |✓|`T: IterFromColumn` newtype|same as `T`
+//! |✓|`T: FromColumnValue`|`String`
+//! |✓|`T: FromColumnValue`|`Categorical(..)`|`dtype-categorical`
+//! |✓|`T: FromColumnValue`|`Enum(..)`|`dtype-categorical`
+//! |✓|`T: FromColumnValue`|`Int8`..`UInt64`, explicit discriminants
 //! |X|X|`Null`
 //! |X|X|`Unknown(..)`|
 //! |X|X|`Object(..)`|
@@ -175,17 +637,27 @@
 
 extern crate self as polars_rows_iter;
 
+mod conversion;
 mod dataframe_rows_iter_ext;
+mod from_column_value;
 mod from_dataframe_row;
 mod iter_from_column;
+#[cfg(feature = "regex")]
+mod matching_columns;
+mod rest_columns;
 #[cfg(any(test, feature = "testing"))]
 pub mod testing;
 
+pub use conversion::*;
 pub use convert_case;
 pub use dataframe_rows_iter_ext::*;
+pub use from_column_value::*;
 pub use from_dataframe_row::*;
 pub use iter_from_column::*;
+#[cfg(feature = "regex")]
+pub use matching_columns::*;
 use polars_rows_iter_derive::impl_tuple_rows_iter;
-pub use polars_rows_iter_derive::FromDataFrameRow;
+pub use polars_rows_iter_derive::{FromColumnValue, FromDataFrameRow, IterFromColumn};
+pub use rest_columns::*;
 
 impl_tuple_rows_iter!(10);
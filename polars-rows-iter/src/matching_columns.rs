@@ -0,0 +1,145 @@
+//! Iteration of all columns matching a regex, used by the code generated for `#[columns(regex = "...")]` fields.
+
+use crate::IterFromColumn;
+use polars::prelude::*;
+use regex::Regex;
+use std::cmp::Ordering;
+
+struct MatchingColumn<'a, T: IterFromColumn<'a>> {
+    name: &'a str,
+    dtype: DataType,
+    iter: Box<dyn Iterator<Item = Option<T::RawInner>> + 'a>,
+}
+
+/// Iterates the raw values of all columns whose name matches a regex, one `Vec` per row.
+/// The columns are resolved once on creation.
+#[doc(hidden)]
+pub struct MatchingColumnsIter<'a, T: IterFromColumn<'a>> {
+    columns: Vec<MatchingColumn<'a, T>>,
+    // Without any matching column there's no iterator that ends with the DataFrame
+    remaining_rows: usize,
+}
+
+impl<'a, T> MatchingColumnsIter<'a, T>
+where
+    T: IterFromColumn<'a>,
+{
+    pub fn new(dataframe: &'a DataFrame, pattern: &str, natural_order: bool) -> PolarsResult<Self> {
+        let regex =
+            Regex::new(pattern).map_err(|err| polars_err!(ComputeError: "Invalid column regex '{pattern}': {err}"))?;
+
+        let mut matching_columns = dataframe
+            .columns()
+            .iter()
+            .filter(|column| regex.is_match(column.name()))
+            .collect::<Vec<_>>();
+
+        if natural_order {
+            matching_columns.sort_by(|a, b| natural_cmp(a.name(), b.name()));
+        }
+
+        let columns = matching_columns
+            .into_iter()
+            .map(|column| {
+                Ok(MatchingColumn {
+                    name: column.name().as_str(),
+                    dtype: column.dtype().clone(),
+                    iter: Box::new(T::create_iter(column)?),
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(Self {
+            columns,
+            remaining_rows: dataframe.height(),
+        })
+    }
+
+    /// The names of the matching columns, in the order of the values.
+    pub fn column_names(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.columns.iter().map(|column| column.name)
+    }
+
+    /// Converts the raw values of a row, paired with the names of their columns.
+    pub fn get_values<V>(&self, values: Vec<Option<T::RawInner>>) -> PolarsResult<Vec<(&'a str, V)>>
+    where
+        V: IterFromColumn<'a, RawInner = T::RawInner>,
+    {
+        self.columns
+            .iter()
+            .zip(values)
+            .map(|(column, value)| Ok((column.name, V::get_value(value, column.name, &column.dtype)?)))
+            .collect()
+    }
+}
+
+impl<'a, T> Iterator for MatchingColumnsIter<'a, T>
+where
+    T: IterFromColumn<'a>,
+{
+    type Item = Vec<Option<T::RawInner>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_rows == 0 {
+            return None;
+        }
+        self.remaining_rows -= 1;
+
+        self.columns.iter_mut().map(|column| column.iter.next()).collect()
+    }
+}
+
+/// Compares names with digit runs by their numeric value, so `feat_2` is ordered before `feat_10`.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a;
+    let mut b = b;
+
+    loop {
+        let (Some(a_char), Some(b_char)) = (a.chars().next(), b.chars().next()) else {
+            return a.len().cmp(&b.len());
+        };
+
+        if a_char.is_ascii_digit() && b_char.is_ascii_digit() {
+            let a_end = a.find(|c: char| !c.is_ascii_digit()).unwrap_or(a.len());
+            let b_end = b.find(|c: char| !c.is_ascii_digit()).unwrap_or(b.len());
+            let a_digits = a[..a_end].trim_start_matches('0');
+            let b_digits = b[..b_end].trim_start_matches('0');
+
+            let ordering = a_digits
+                .len()
+                .cmp(&b_digits.len())
+                .then_with(|| a_digits.cmp(b_digits))
+                .then_with(|| a_end.cmp(&b_end));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+
+            a = &a[a_end..];
+            b = &b[b_end..];
+        } else {
+            let ordering = a_char.cmp(&b_char);
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+
+            a = &a[a_char.len_utf8()..];
+            b = &b[b_char.len_utf8()..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn natural_order_compares_numbers_by_value() {
+        let mut names = vec!["feat_10", "feat_2", "feat_1", "feat_02", "a", "feat_1b", "feat_1a"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+
+        assert_eq!(
+            names,
+            vec!["a", "feat_1", "feat_1a", "feat_1b", "feat_2", "feat_02", "feat_10"]
+        );
+    }
+}
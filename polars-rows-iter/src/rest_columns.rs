@@ -0,0 +1,52 @@
+//! Iteration of the columns not read by any other field, used by the code generated for `#[column(rest)]` fields.
+
+use polars::{prelude::*, series::SeriesIter};
+
+struct RestColumn<'a> {
+    name: &'a str,
+    iter: SeriesIter<'a>,
+}
+
+/// Iterates the values of all columns that aren't claimed by other fields, paired with their column names.
+/// The columns are resolved once on creation.
+#[doc(hidden)]
+pub struct RestColumnsIter<'a> {
+    columns: Vec<RestColumn<'a>>,
+    // Without any unclaimed column there's no iterator that ends with the DataFrame
+    remaining_rows: usize,
+}
+
+impl<'a> RestColumnsIter<'a> {
+    pub fn new(dataframe: &'a DataFrame, claimed_column_names: &[String]) -> Self {
+        let columns = dataframe
+            .columns()
+            .iter()
+            .filter(|column| !claimed_column_names.iter().any(|name| name == column.name().as_str()))
+            .map(|column| RestColumn {
+                name: column.name().as_str(),
+                iter: column.as_materialized_series().iter(),
+            })
+            .collect();
+
+        Self {
+            columns,
+            remaining_rows: dataframe.height(),
+        }
+    }
+}
+
+impl<'a> Iterator for RestColumnsIter<'a> {
+    type Item = Vec<(&'a str, AnyValue<'a>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_rows == 0 {
+            return None;
+        }
+        self.remaining_rows -= 1;
+
+        self.columns
+            .iter_mut()
+            .map(|column| Some((column.name, column.iter.next()?)))
+            .collect()
+    }
+}
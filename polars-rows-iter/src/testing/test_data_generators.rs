@@ -10,6 +10,9 @@ use std::collections::HashMap;
 pub type IsOptional = bool;
 
 const TIME64_MAX_VALUE: i64 = 24 * 60 * 60 * 1_000_000_000;
+// days since epoch of 0001-01-01 and 9999-12-31
+const DATE32_MIN_VALUE: i32 = -719_162;
+const DATE32_MAX_VALUE: i32 = 2_932_896;
 
 #[derive(Debug, Clone)]
 pub struct ColumnType(pub DataType, pub IsOptional);
@@ -124,6 +127,11 @@ pub fn create_column(name: &str, dtype: &DataType, optional: IsOptional, height:
             true => Column::new(name, create_values(height, || create_optional_number::<u64>(rng))),
             false => Column::new(name, create_values(height, || rng.random::<u64>())),
         },
+        #[cfg(feature = "dtype-u128")]
+        DataType::UInt128 => match optional {
+            true => Column::new(name, create_values(height, || create_optional_number::<u128>(rng))),
+            false => Column::new(name, create_values(height, || rng.random::<u128>())),
+        },
         DataType::Int8 => match optional {
             true => Column::new(name, create_values(height, || create_optional_number::<i8>(rng))),
             false => Column::new(name, create_values(height, || rng.random::<i8>())),
@@ -140,6 +148,11 @@ pub fn create_column(name: &str, dtype: &DataType, optional: IsOptional, height:
             true => Column::new(name, create_values(height, || create_optional_number::<i64>(rng))),
             false => Column::new(name, create_values(height, || rng.random::<i64>())),
         },
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => match optional {
+            true => Column::new(name, create_values(height, || create_optional_number::<i128>(rng))),
+            false => Column::new(name, create_values(height, || rng.random::<i128>())),
+        },
         DataType::Float32 => match optional {
             true => Column::new(name, create_values(height, || create_optional_number::<f32>(rng))),
             false => Column::new(name, create_values(height, || rng.random::<f32>())),
@@ -189,12 +202,20 @@ pub fn create_column(name: &str, dtype: &DataType, optional: IsOptional, height:
                 .unwrap(),
         },
         DataType::Date => match optional {
-            true => Column::new(name, create_values(height, || create_optional_number::<i32>(rng)))
-                .cast(&DataType::Date)
-                .unwrap(),
-            false => Column::new(name, create_values(height, || rng.random::<i32>()))
-                .cast(&DataType::Date)
-                .unwrap(),
+            true => Column::new(
+                name,
+                create_values(height, || {
+                    create_optional(rng, |rng| rng.random_range(DATE32_MIN_VALUE..=DATE32_MAX_VALUE))
+                }),
+            )
+            .cast(&DataType::Date)
+            .unwrap(),
+            false => Column::new(
+                name,
+                create_values(height, || rng.random_range(DATE32_MIN_VALUE..=DATE32_MAX_VALUE)),
+            )
+            .cast(&DataType::Date)
+            .unwrap(),
         },
         DataType::Time => match optional {
             true => Column::new(
@@ -0,0 +1,118 @@
+use polars::df;
+use polars::prelude::*;
+use polars_rows_iter::*;
+
+const UNKNOWN: &str = "unknown";
+
+fn parse_flag(value: &str) -> PolarsResult<bool> {
+    Ok(value == "y")
+}
+
+#[test]
+fn test_defaults_replace_nulls() {
+    let df = df!(
+        "id" => [1i64, 2],
+        "retries" => [Some(3u32), None],
+        "name" => [None, Some("b")],
+        "ratio" => [None, Some(0.5f64)],
+        "port" => [Some("8080"), None],
+        "flag" => [Some("n"), None],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Config<'a> {
+        id: i64,
+        #[column(default)]
+        retries: u32,
+        #[column(default = UNKNOWN)]
+        name: &'a str,
+        #[column(default = 1.0, cast)]
+        ratio: f32,
+        #[column(parse, default = 80)]
+        port: u16,
+        #[column(with = parse_flag, from = &str, default = true)]
+        flag: bool,
+    }
+
+    let rows = df
+        .rows_iter::<Config>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Config {
+                id: 1,
+                retries: 3,
+                name: "unknown",
+                ratio: 1.0,
+                port: 8080,
+                flag: false
+            },
+            Config {
+                id: 2,
+                retries: 0,
+                name: "b",
+                ratio: 0.5,
+                port: 80,
+                flag: true
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_default_expressions_can_build_owned_values() {
+    let df = df!("label" => [Some("a"), None]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow)]
+    struct Row {
+        #[column(default = String::from("n/a"))]
+        label: String,
+    }
+
+    let labels = df
+        .rows_iter::<Row>()
+        .unwrap()
+        .map(|row| row.map(|row| row.label))
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(labels, vec!["a", "n/a"]);
+}
+
+#[test]
+fn test_default_expressions_are_not_limited_to_paths() {
+    let df = df!("count" => [Some(1i64), None]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Row {
+        #[column(default = { 2 * 21 })]
+        count: i64,
+        #[column(skip, default = [0u8; 2])]
+        flags: [u8; 2],
+    }
+
+    let rows = df
+        .rows_iter::<Row>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                count: 1,
+                flags: [0, 0]
+            },
+            Row {
+                count: 42,
+                flags: [0, 0]
+            },
+        ]
+    );
+}
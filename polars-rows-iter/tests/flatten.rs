@@ -0,0 +1,152 @@
+use polars::df;
+use polars::prelude::*;
+use polars_rows_iter::*;
+
+#[derive(Debug, FromDataFrameRow, PartialEq)]
+struct Address<'a> {
+    street: &'a str,
+    city: Option<&'a str>,
+}
+
+#[derive(Debug, FromDataFrameRow, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[test]
+fn test_flattened_structs_with_prefixes() {
+    let df = df!(
+        "id" => [1i64, 2],
+        "billing_street" => ["Main St 1", "Side St 2"],
+        "billing_city" => [Some("Berlin"), None],
+        "shipping_street" => ["Harbor 3", "Dock 4"],
+        "shipping_city" => [None, Some("Hamburg")],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Order<'a> {
+        id: i64,
+        #[column(flatten, prefix = "billing_")]
+        billing: Address<'a>,
+        #[column(flatten, prefix = "shipping_")]
+        shipping: Address<'a>,
+    }
+
+    let rows = df
+        .rows_iter::<Order>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Order {
+                id: 1,
+                billing: Address {
+                    street: "Main St 1",
+                    city: Some("Berlin")
+                },
+                shipping: Address {
+                    street: "Harbor 3",
+                    city: None
+                },
+            },
+            Order {
+                id: 2,
+                billing: Address {
+                    street: "Side St 2",
+                    city: None
+                },
+                shipping: Address {
+                    street: "Dock 4",
+                    city: Some("Hamburg")
+                },
+            },
+        ]
+    );
+
+    assert_eq!(Order::get_column_names(), vec!["id"]);
+}
+
+#[test]
+fn test_flattened_column_names_follow_outer_transformations() {
+    let df = df!(
+        "col_LeftX" => [1.0f64],
+        "col_LeftY" => [2.0f64],
+        "col_X" => [3.0f64],
+        "col_Y" => [4.0f64],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    #[from_dataframe(convert_case(Pascal), prefix("col_"))]
+    struct Segment {
+        #[column(flatten, prefix = "left_")]
+        left: Point,
+        #[column(flatten)]
+        right: Point,
+    }
+
+    let segment = df.rows_iter::<Segment>().unwrap().next().unwrap().unwrap();
+
+    assert_eq!(
+        segment,
+        Segment {
+            left: Point { x: 1.0, y: 2.0 },
+            right: Point { x: 3.0, y: 4.0 },
+        }
+    );
+}
+
+#[test]
+fn test_flattened_struct_errors_name_prefixed_column() {
+    let df = df!("left_x" => [1.0f64]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow)]
+    struct Line {
+        #[column(flatten, prefix = "left_")]
+        _left: Point,
+    }
+
+    let err_msg = df.rows_iter::<Line>().err().unwrap().to_string();
+
+    assert!(err_msg.contains("left_y"), "{err_msg}");
+}
+
+#[test]
+fn test_column_overrides_do_not_reach_flattened_structs() {
+    let df = df!(
+        "order_id" => [1i64],
+        "x" => [5.0f64],
+        "point_x" => [1.0f64],
+        "point_y" => [2.0f64],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Marker {
+        id: i64,
+        #[column(flatten, prefix = "point_")]
+        point: Point,
+    }
+
+    let marker = df
+        .rows_iter_with_columns::<Marker>(|columns| columns.id("order_id"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        marker,
+        Marker {
+            id: 1,
+            point: Point { x: 1.0, y: 2.0 }
+        }
+    );
+
+    assert_eq!(Marker::get_column_names(), vec!["id"]);
+}
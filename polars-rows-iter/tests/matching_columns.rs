@@ -0,0 +1,142 @@
+#![cfg(feature = "regex")]
+
+use polars::df;
+use polars::prelude::*;
+use polars_rows_iter::*;
+use std::collections::{BTreeMap, HashMap};
+
+#[test]
+fn test_vec_fields_read_matching_columns_in_column_order() {
+    let df = df!(
+        "id" => [1i64, 2],
+        "feat_10" => [10.0f32, 20.0],
+        "feat_2" => [2.0f32, 4.0],
+        "feat_x" => [0.0f32, 0.0],
+        "feat_1" => [1.0f32, 2.0],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Row {
+        id: i64,
+        #[columns(regex = "^feat_\\d+$")]
+        features: Vec<f32>,
+        #[columns(regex = "^feat_\\d+$", order = "natural")]
+        sorted_features: Vec<f32>,
+    }
+
+    let rows = df
+        .rows_iter::<Row>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                id: 1,
+                features: vec![10.0, 2.0, 1.0],
+                sorted_features: vec![1.0, 2.0, 10.0],
+            },
+            Row {
+                id: 2,
+                features: vec![20.0, 4.0, 2.0],
+                sorted_features: vec![2.0, 4.0, 20.0],
+            },
+        ]
+    );
+
+    assert_eq!(Row::get_column_names(), vec!["id"]);
+}
+
+#[test]
+fn test_map_fields_are_keyed_by_column_name() {
+    let df = df!(
+        "sensor_a" => [Some(1i32), None],
+        "sensor_b" => [Some(2i32), Some(3)],
+        "label" => ["x", "y"],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Row<'a> {
+        #[columns(regex = "^sensor_")]
+        sensors: HashMap<String, Option<i32>>,
+        #[columns(regex = "^sensor_")]
+        sorted_sensors: BTreeMap<&'a str, Option<i32>>,
+        label: &'a str,
+    }
+
+    let rows = df
+        .rows_iter::<Row>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                sensors: HashMap::from([("sensor_a".to_string(), Some(1)), ("sensor_b".to_string(), Some(2))]),
+                sorted_sensors: BTreeMap::from([("sensor_a", Some(1)), ("sensor_b", Some(2))]),
+                label: "x",
+            },
+            Row {
+                sensors: HashMap::from([("sensor_a".to_string(), None), ("sensor_b".to_string(), Some(3))]),
+                sorted_sensors: BTreeMap::from([("sensor_a", None), ("sensor_b", Some(3))]),
+                label: "y",
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_without_matching_columns_fields_are_empty() {
+    let df = df!("id" => [1i64, 2]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Row {
+        #[columns(regex = "^feat_")]
+        features: Vec<f64>,
+    }
+
+    let rows = df
+        .rows_iter::<Row>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(rows, vec![Row { features: vec![] }, Row { features: vec![] }]);
+}
+
+#[test]
+fn test_matching_columns_errors() {
+    #[derive(Debug, FromDataFrameRow)]
+    struct Row {
+        #[columns(regex = "^feat_")]
+        _features: Vec<f64>,
+    }
+
+    let df = df!("feat_1" => [Some(1.0f64), None]).unwrap();
+    let mut rows = df.rows_iter::<Row>().unwrap();
+
+    assert!(rows.next().unwrap().is_ok());
+    assert_eq!(
+        rows.next().unwrap().unwrap_err().to_string(),
+        "Found unexpected None/null value in column 'feat_1' with mandatory values!"
+    );
+
+    // Literal regexes are already checked by the derive macro
+    const INVALID_PATTERN: &str = "feat_(";
+
+    #[derive(Debug, FromDataFrameRow)]
+    struct InvalidRow {
+        #[columns(regex = INVALID_PATTERN)]
+        _features: Vec<f64>,
+    }
+
+    let err_msg = df.rows_iter::<InvalidRow>().err().unwrap().to_string();
+
+    assert!(err_msg.starts_with("Invalid column regex 'feat_('"), "{err_msg}");
+}
@@ -0,0 +1,105 @@
+use polars::df;
+use polars::prelude::*;
+use polars_rows_iter::*;
+
+#[derive(Debug, FromDataFrameRow, PartialEq)]
+struct Event<'a> {
+    id: i64,
+    #[column(missing = "none")]
+    source: Option<&'a str>,
+    #[column(missing = default)]
+    retries: u32,
+    #[column(missing = default, default = 10)]
+    priority: u8,
+}
+
+#[test]
+fn test_missing_columns_yield_constant_values() {
+    let df = df!("id" => [1i64, 2]).unwrap();
+
+    let rows = df
+        .rows_iter::<Event>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Event {
+                id: 1,
+                source: None,
+                retries: 0,
+                priority: 10
+            },
+            Event {
+                id: 2,
+                source: None,
+                retries: 0,
+                priority: 10
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_present_columns_are_read() {
+    let df = df!(
+        "id" => [1i64, 2],
+        "source" => [Some("api"), None],
+        "retries" => [3u32, 4],
+        "priority" => [None, Some(1u8)],
+    )
+    .unwrap();
+
+    let rows = df
+        .rows_iter::<Event>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Event {
+                id: 1,
+                source: Some("api"),
+                retries: 3,
+                priority: 10
+            },
+            Event {
+                id: 2,
+                source: None,
+                retries: 4,
+                priority: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_rows_are_counted_without_any_column() {
+    let df = df!("other" => [1i64, 2, 3]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow)]
+    struct Row {
+        #[column(missing = default)]
+        value: i64,
+    }
+
+    let values = df
+        .rows_iter::<Row>()
+        .unwrap()
+        .map(|row| row.map(|row| row.value))
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(values, vec![0, 0, 0]);
+}
+
+#[test]
+fn test_missing_columns_still_fail_without_attribute() {
+    let df = df!("source" => ["api"]).unwrap();
+
+    assert!(df.rows_iter::<Event>().is_err());
+}
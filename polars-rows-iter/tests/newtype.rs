@@ -0,0 +1,73 @@
+use polars::df;
+use polars::prelude::*;
+use polars_rows_iter::*;
+
+#[derive(Debug, PartialEq, IterFromColumn)]
+struct UserId(i64);
+
+#[derive(Debug, PartialEq, IterFromColumn)]
+struct Email<'a>(&'a str);
+
+#[derive(Debug, PartialEq, IterFromColumn)]
+struct Score {
+    value: f64,
+}
+
+#[test]
+fn test_newtypes_in_row_struct() {
+    let df = df!(
+        "id" => [1i64, 2],
+        "email" => ["a@example.com", "b@example.com"],
+        "backup_email" => [None, Some("c@example.com")],
+        "score" => [Some(0.5f64), None],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct User<'a> {
+        id: UserId,
+        email: Email<'a>,
+        backup_email: Option<Email<'a>>,
+        score: Option<Score>,
+    }
+
+    let rows: Vec<User> = df.rows_iter::<User>().unwrap().map(|r| r.unwrap()).collect();
+
+    assert_eq!(
+        rows,
+        vec![
+            User {
+                id: UserId(1),
+                email: Email("a@example.com"),
+                backup_email: None,
+                score: Some(Score { value: 0.5 }),
+            },
+            User {
+                id: UserId(2),
+                email: Email("b@example.com"),
+                backup_email: Some(Email("c@example.com")),
+                score: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_newtype_errors_come_from_inner_type() {
+    let df = df!("id" => [Some(1i64), None]).unwrap();
+
+    let err = df
+        .scalar_iter::<UserId>("id")
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Found unexpected None/null value in column 'id' with mandatory values!"
+    );
+
+    let df = df!("id" => ["1"]).unwrap();
+
+    assert!(df.scalar_iter::<UserId>("id").is_err());
+}
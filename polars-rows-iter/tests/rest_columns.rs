@@ -0,0 +1,218 @@
+use polars::df;
+use polars::prelude::*;
+use polars_rows_iter::*;
+
+#[test]
+fn test_rest_fields_get_unclaimed_columns() {
+    let df = df!(
+        "id" => [1i64, 2],
+        "payload" => ["a", "b"],
+        "name" => ["x", "y"],
+        "score" => [Some(0.5f64), None],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Record<'a> {
+        id: i64,
+        #[column(rest)]
+        extra: Vec<(&'a str, AnyValue<'a>)>,
+        name: &'a str,
+    }
+
+    let rows = df
+        .rows_iter::<Record>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Record {
+                id: 1,
+                extra: vec![("payload", AnyValue::String("a")), ("score", AnyValue::Float64(0.5))],
+                name: "x",
+            },
+            Record {
+                id: 2,
+                extra: vec![("payload", AnyValue::String("b")), ("score", AnyValue::Null)],
+                name: "y",
+            },
+        ]
+    );
+
+    assert_eq!(Record::get_column_names(), vec!["id", "name"]);
+}
+
+#[cfg(feature = "regex")]
+#[derive(Debug, FromDataFrameRow, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_rest_fields_exclude_flattened_and_matching_columns() {
+    use std::collections::HashMap;
+
+    let df = df!(
+        "Id" => [1i64],
+        "PosX" => [1.0f64],
+        "PosY" => [2.0f64],
+        "Feat1" => [3i32],
+        "Comment" => ["ok"],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    #[from_dataframe(convert_case(Pascal))]
+    struct Record<'a> {
+        id: i64,
+        #[column(flatten, prefix = "pos_")]
+        pos: Point,
+        #[columns(regex = "^Feat")]
+        features: Vec<i32>,
+        #[column(rest)]
+        extra: HashMap<String, AnyValue<'a>>,
+    }
+
+    let record = df.rows_iter::<Record>().unwrap().next().unwrap().unwrap();
+
+    assert_eq!(
+        record,
+        Record {
+            id: 1,
+            pos: Point { x: 1.0, y: 2.0 },
+            features: vec![3],
+            extra: HashMap::from([("Comment".to_string(), AnyValue::String("ok"))]),
+        }
+    );
+}
+
+#[test]
+fn test_rest_fields_in_tagged_enums_exclude_the_tag_column() {
+    let df = df!(
+        "kind" => ["Circle"],
+        "radius" => [1.0f64],
+        "color" => ["red"],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    #[from_dataframe(tag = "kind")]
+    enum Shape<'a> {
+        Circle {
+            radius: f64,
+            #[column(rest)]
+            extra: Vec<(String, AnyValue<'a>)>,
+        },
+    }
+
+    let shape = df.rows_iter::<Shape>().unwrap().next().unwrap().unwrap();
+
+    assert_eq!(
+        shape,
+        Shape::Circle {
+            radius: 1.0,
+            extra: vec![("color".to_string(), AnyValue::String("red"))],
+        }
+    );
+}
+
+#[test]
+fn test_rest_only_structs_have_a_row_per_dataframe_row() {
+    let df = df!("a" => [1i64, 2], "b" => [true, false]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow)]
+    struct Record<'a> {
+        #[column(rest)]
+        extra: Vec<(&'a str, AnyValue<'a>)>,
+    }
+
+    let rows = df
+        .rows_iter::<Record>()
+        .unwrap()
+        .map(|row| row.map(|row| row.extra))
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            vec![("a", AnyValue::Int64(1)), ("b", AnyValue::Boolean(true))],
+            vec![("a", AnyValue::Int64(2)), ("b", AnyValue::Boolean(false))],
+        ]
+    );
+}
+
+#[test]
+fn test_rest_fields_of_chunked_and_scalar_columns() {
+    let mut df = df!("id" => [1i64], "name" => ["a"]).unwrap();
+    df.vstack_mut(&df!("id" => [2i64], "name" => ["b"]).unwrap()).unwrap();
+    df.with_column(Column::new_scalar("source".into(), Scalar::from(7i32), 2))
+        .unwrap();
+
+    assert_eq!(df.column("name").unwrap().n_chunks(), 2);
+
+    #[derive(Debug, FromDataFrameRow)]
+    struct Record<'a> {
+        id: i64,
+        #[column(rest)]
+        extra: Vec<(&'a str, AnyValue<'a>)>,
+    }
+
+    let rows = df
+        .rows_iter::<Record>()
+        .unwrap()
+        .map(|row| row.map(|row| (row.id, row.extra)))
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            (1, vec![("name", AnyValue::String("a")), ("source", AnyValue::Int32(7))]),
+            (2, vec![("name", AnyValue::String("b")), ("source", AnyValue::Int32(7))]),
+        ]
+    );
+}
+
+#[test]
+fn test_rest_fields_exclude_transformed_column_names() {
+    let df = df!(
+        "col_id" => [1i64, 2],
+        "id" => [10i64, 20],
+    )
+    .unwrap();
+
+    // Structs with rest fields can't be flattened, so they're always read without an outer struct
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    #[from_dataframe(prefix("col_"))]
+    struct Record<'a> {
+        id: i64,
+        #[column(rest)]
+        extra: Vec<(&'a str, AnyValue<'a>)>,
+    }
+
+    let rows = df
+        .rows_iter::<Record>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Record {
+                id: 1,
+                extra: vec![("id", AnyValue::Int64(10))],
+            },
+            Record {
+                id: 2,
+                extra: vec![("id", AnyValue::Int64(20))],
+            },
+        ]
+    );
+}
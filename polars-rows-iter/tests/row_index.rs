@@ -0,0 +1,144 @@
+use polars::df;
+use polars::prelude::*;
+use polars_rows_iter::*;
+
+#[test]
+fn test_row_index_fields_get_the_row_position() {
+    let df = df!("name" => ["a", "b", "c"]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Row<'a> {
+        #[row_index]
+        index: usize,
+        #[row_index]
+        index_u32: u32,
+        #[row_index]
+        index_idx: IdxSize,
+        name: &'a str,
+    }
+
+    let rows = df
+        .rows_iter::<Row>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                index: 0,
+                index_u32: 0,
+                index_idx: 0,
+                name: "a"
+            },
+            Row {
+                index: 1,
+                index_u32: 1,
+                index_idx: 1,
+                name: "b"
+            },
+            Row {
+                index: 2,
+                index_u32: 2,
+                index_idx: 2,
+                name: "c"
+            },
+        ]
+    );
+
+    assert_eq!(Row::get_column_names(), vec!["name"]);
+}
+
+#[derive(Debug, FromDataFrameRow, PartialEq)]
+#[from_dataframe(row_index_offset = 1)]
+struct Line<'a> {
+    #[row_index]
+    line: u64,
+    text: &'a str,
+}
+
+#[test]
+fn test_row_index_offset_and_skipped_rows() {
+    let df = df!("text" => ["a", "b", "c", "d"]).unwrap();
+
+    let rows = df
+        .rows_iter::<Line>()
+        .unwrap()
+        .skip(1)
+        .step_by(2)
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(rows, vec![Line { line: 2, text: "b" }, Line { line: 4, text: "d" }]);
+
+    // Rows that fail still count
+    let df = df!("text" => [None, Some("b")]).unwrap();
+    let mut rows = df.rows_iter::<Line>().unwrap();
+
+    assert!(rows.next().unwrap().is_err());
+    assert_eq!(rows.next().unwrap().unwrap(), Line { line: 2, text: "b" });
+}
+
+#[test]
+fn test_row_index_in_tagged_enums_and_flattened_structs() {
+    let df = df!(
+        "kind" => ["Start", "Line", "Line"],
+        "text" => ["x", "a", "b"],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    #[from_dataframe(tag = "kind")]
+    enum Entry<'a> {
+        Start {},
+        Line {
+            #[row_index]
+            position: usize,
+            #[column(flatten)]
+            line: Line<'a>,
+        },
+    }
+
+    let rows = df
+        .rows_iter::<Entry>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Entry::Start {},
+            Entry::Line {
+                position: 1,
+                line: Line { line: 2, text: "a" }
+            },
+            Entry::Line {
+                position: 2,
+                line: Line { line: 3, text: "b" }
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_row_index_out_of_range() {
+    let df = df!("value" => [1i64, 2]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow)]
+    #[from_dataframe(row_index_offset = 255)]
+    struct Row {
+        #[row_index]
+        index: u8,
+    }
+
+    let mut rows = df.rows_iter::<Row>().unwrap();
+
+    assert_eq!(rows.next().unwrap().unwrap().index, 255);
+    assert_eq!(
+        rows.next().unwrap().unwrap_err().to_string(),
+        "Row index 256 is out of range for field 'index'"
+    );
+    assert!(rows.next().is_none());
+}
@@ -0,0 +1,110 @@
+use polars::df;
+use polars::prelude::*;
+use polars_rows_iter::*;
+
+#[test]
+fn test_skipped_fields_are_not_read() {
+    let df = df!(
+        "id" => [1i64, 2],
+        "name" => ["a", "b"],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct User<'a> {
+        id: i64,
+        name: &'a str,
+        #[column(skip)]
+        visits: Vec<u64>,
+        #[column(skip, default = String::from("pending"))]
+        status: String,
+    }
+
+    let rows = df
+        .rows_iter::<User>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            User {
+                id: 1,
+                name: "a",
+                visits: vec![],
+                status: "pending".to_string()
+            },
+            User {
+                id: 2,
+                name: "b",
+                visits: vec![],
+                status: "pending".to_string()
+            },
+        ]
+    );
+
+    assert_eq!(User::get_column_names(), vec!["id", "name"]);
+}
+
+#[test]
+fn test_skipped_fields_in_tagged_enums() {
+    let df = df!(
+        "kind" => ["Circle", "Square"],
+        "size" => [1.0f64, 2.0],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    #[from_dataframe(tag = "kind")]
+    enum Shape {
+        Circle {
+            #[column("size")]
+            radius: f64,
+            #[column(skip, default = 1)]
+            layer: u8,
+        },
+        Square {
+            #[column("size")]
+            side: f64,
+            #[column(skip)]
+            layer: u8,
+        },
+    }
+
+    let rows = df
+        .rows_iter::<Shape>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Shape::Circle { radius: 1.0, layer: 1 },
+            Shape::Square { side: 2.0, layer: 0 },
+        ]
+    );
+}
+
+#[test]
+fn test_structs_with_only_skipped_fields_have_a_row_per_dataframe_row() {
+    let df = df!("id" => [1i64, 2, 3]).unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    struct Marker {
+        #[column(skip)]
+        seen: bool,
+    }
+
+    let rows = df
+        .rows_iter::<Marker>()
+        .unwrap()
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![Marker { seen: false }, Marker { seen: false }, Marker { seen: false }]
+    );
+}
@@ -0,0 +1,137 @@
+use polars::df;
+use polars_rows_iter::*;
+
+#[derive(Debug, FromDataFrameRow, PartialEq)]
+#[from_dataframe(tag = "kind")]
+enum Event<'a> {
+    Click {
+        x: i32,
+        y: i32,
+    },
+    Purchase {
+        amount: f64,
+        #[column("product")]
+        product_name: &'a str,
+    },
+    #[from_dataframe(rename = "logout")]
+    Logout,
+}
+
+#[test]
+fn test_variant_is_selected_by_tag() {
+    let df = df!(
+        "kind" => ["Click", "Purchase", "logout", "Click"],
+        "x" => [Some(1i32), None, None, Some(3)],
+        "y" => [Some(2i32), None, None, Some(4)],
+        "amount" => [None, Some(9.99f64), None, None],
+        "product" => [None, Some("book"), None, None],
+    )
+    .unwrap();
+
+    let rows: Vec<Event> = df.rows_iter::<Event>().unwrap().map(|r| r.unwrap()).collect();
+
+    assert_eq!(
+        rows,
+        vec![
+            Event::Click { x: 1, y: 2 },
+            Event::Purchase {
+                amount: 9.99,
+                product_name: "book"
+            },
+            Event::Logout,
+            Event::Click { x: 3, y: 4 },
+        ]
+    );
+}
+
+#[test]
+fn test_null_in_selected_variant_column_is_an_error() {
+    let df = df!(
+        "kind" => ["Purchase"],
+        "x" => [Some(1i32)],
+        "y" => [Some(2i32)],
+        "amount" => [None::<f64>],
+        "product" => ["book"],
+    )
+    .unwrap();
+
+    let err = df.rows_iter::<Event>().unwrap().next().unwrap().unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Found unexpected None/null value in column 'amount' with mandatory values!"
+    );
+}
+
+#[test]
+fn test_unknown_tag_is_an_error() {
+    let df = df!(
+        "kind" => ["Scroll"],
+        "x" => [1i32],
+        "y" => [2i32],
+        "amount" => [1.0f64],
+        "product" => ["book"],
+    )
+    .unwrap();
+
+    let err = df.rows_iter::<Event>().unwrap().next().unwrap().unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Value 'Scroll' in tag column 'kind' does not match any variant of Event"
+    );
+}
+
+#[test]
+fn test_shared_field_names_with_transformations() {
+    let df = df!(
+        "Kind" => ["Deposit", "Withdrawal"],
+        "evt_Account" => [7u32, 8],
+        "evt_Amount" => [100i64, 50],
+    )
+    .unwrap();
+
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    #[from_dataframe(tag = "Kind", convert_case(Pascal), prefix("evt_"))]
+    enum Transaction {
+        Deposit { account: u32, amount: i64 },
+        Withdrawal { account: u32, amount: i64 },
+    }
+
+    let rows: Vec<Transaction> = df.rows_iter::<Transaction>().unwrap().map(|r| r.unwrap()).collect();
+
+    assert_eq!(
+        rows,
+        vec![
+            Transaction::Deposit {
+                account: 7,
+                amount: 100
+            },
+            Transaction::Withdrawal { account: 8, amount: 50 },
+        ]
+    );
+}
+
+#[test]
+fn test_column_names_are_unique_per_column() {
+    #[derive(Debug, FromDataFrameRow, PartialEq)]
+    #[from_dataframe(tag = "kind")]
+    enum Measurement {
+        Temperature {
+            #[column("celsius")]
+            value: f64,
+            sensor: u32,
+        },
+        Humidity {
+            #[column("percent")]
+            value: f64,
+            #[column("sensor")]
+            sensor_id: u32,
+        },
+    }
+
+    assert_eq!(
+        Measurement::get_column_names(),
+        vec!["kind", "celsius", "sensor", "percent"]
+    );
+}